@@ -1,20 +1,22 @@
 #![allow(unused)]
-#![feature(iter_collect_into, iter_intersperse, file_create_new)]
+#![feature(iter_collect_into, iter_intersperse)]
+#![allow(clippy::needless_return)]
 
 use num_format::{Locale, ToFormattedString};
 use rand::{
-    distributions::{Alphanumeric, Standard},
+    distributions::{Alphanumeric, Distribution, Standard},
     random, thread_rng, Rng,
 };
 use std::{
     collections::{BTreeMap, HashMap},
     fs::File,
+    hash::Hash,
     hint::black_box,
     io::{BufWriter, Seek, SeekFrom, Write},
     ops::Deref,
     time::{Duration, Instant},
 };
-use vector_mapp::{binary::BinaryMap, vec::VecMap};
+use vector_mapp::{binary::BinaryMap, soa::SoaVecMap, vec::VecMap};
 
 pub struct Bencher {
     warmup: Duration,
@@ -24,7 +26,7 @@ pub struct Bencher {
 
 impl Bencher {
     #[inline(never)]
-    pub fn iter<T, F: FnMut() -> T>(&mut self, name: &str, param: usize, mut f: F) {
+    pub fn iter<T, F: FnMut() -> T>(&mut self, name: &'static str, param: usize, mut f: F) {
         // Warmup
         // println!("Warming up '{name}' [{param}] for {:?}", &self.warmup);
         let now = Instant::now();
@@ -54,7 +56,7 @@ impl Bencher {
         let average = Duration::from_secs_f64(delta.as_secs_f64() / (runs as f64));
         println!("Benchmarked '{name}' [{param}]: {average:?}");
         // println!();
-        self.result[name].push(average);
+        self.result.entry(name).or_default().push(average);
     }
 
     #[inline]
@@ -152,15 +154,18 @@ fn insert_prealloc_with_size(size: usize, b: &mut Bencher) {
     });
 }
 
-fn search_with_size(size: usize, chance: f64, b: &mut Bencher) {
+fn search_with_size<K: Copy + Eq + Hash + Ord>(size: usize, chance: f64, b: &mut Bencher)
+where
+    Standard: Distribution<K>,
+{
     let entries = thread_rng()
-        .sample_iter(Standard)
+        .sample_iter::<(K, u32), _>(Standard)
         .take(size)
-        .collect::<Vec<(u32, u32)>>();
+        .collect::<Vec<_>>();
 
     let searches = (0..size)
         .map(|_| match thread_rng().gen_bool(chance) {
-            false => random::<u32>(),
+            false => random::<K>(),
             true => unsafe { entries.get_unchecked(thread_rng().gen_range(0..size)).0 },
         })
         .collect::<Vec<_>>();
@@ -186,6 +191,14 @@ fn search_with_size(size: usize, chance: f64, b: &mut Bencher) {
         }
     });
 
+    // Stores its keys back to back, which the `simd` feature loads straight into vectors
+    let soavecmap = entries.iter().copied().collect::<SoaVecMap<_, _>>();
+    b.iter("soavecmap", size, || {
+        for key in searches.iter() {
+            black_box(soavecmap.get(key));
+        }
+    });
+
     let binarymap = entries.iter().copied().collect::<BinaryMap<_, _>>();
     b.iter("binarymap", size, || {
         for key in searches.iter() {
@@ -254,13 +267,19 @@ pub fn main() {
         s.spawn(|| calculate("insert_prealloc", iter.clone(), insert_prealloc_with_size).unwrap());
         s.spawn(|| {
             calculate("search_50", iter.clone(), |size, b| {
-                search_with_size(size, 0.5, b)
+                search_with_size::<u32>(size, 0.5, b)
             })
             .unwrap()
         });
 
+        // The sizes where a linear search competes with hashing, for every key width of the `simd` feature
+        s.spawn(|| calculate("search_100_u8", 16..=128, |size, b| search_with_size::<u8>(size, 1., b)).unwrap());
+        s.spawn(|| calculate("search_100_u16", 16..=128, |size, b| search_with_size::<u16>(size, 1., b)).unwrap());
+        s.spawn(|| calculate("search_100_u64", 16..=128, |size, b| search_with_size::<u64>(size, 1., b)).unwrap());
+        s.spawn(|| calculate("search_100_char", 16..=128, |size, b| search_with_size::<char>(size, 1., b)).unwrap());
+
         calculate("search_100", iter.clone(), |size, b| {
-            search_with_size(size, 1., b)
+            search_with_size::<u32>(size, 1., b)
         })
         .unwrap()
     });
@@ -0,0 +1,140 @@
+use super::{buf::ArrayVec, *};
+use crate::binary::{BinaryMap, IntoKeys, IntoValues};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+/// A map with a fixed capacity of `N` entries, stored inline without any heap allocation.
+/// Entries are kept sorted by key, like in [`BinaryMap`].
+///
+/// It's a [`BinaryMap`] backed by an [`ArrayVec`], which it dereferences to for everything besides its
+/// construction, insertions and the methods that consume it. Insertions return the entry back if the key is new
+/// and the map is full.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArrayBinaryMap<K, V, const N: usize> {
+    inner: BinaryMap<K, V, ArrayVec<(K, V), N>>,
+}
+
+impl<K, V, const N: usize> ArrayBinaryMap<K, V, N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            inner: unsafe { BinaryMap::from_vec_unchecked(ArrayVec::new()) },
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.inner.storage().is_full()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> BinaryMap<K, V, ArrayVec<(K, V), N>> {
+        self.inner
+    }
+
+    #[inline]
+    pub fn into_keys(self) -> IntoKeys<K, V, ArrayVec<(K, V), N>> {
+        self.inner.into_keys()
+    }
+
+    #[inline]
+    pub fn into_values(self) -> IntoValues<K, V, ArrayVec<(K, V), N>> {
+        self.inner.into_values()
+    }
+}
+
+impl<K: Ord, V, const N: usize> ArrayBinaryMap<K, V, N> {
+    /// Inserts the entry, returning the previous value of the key.
+    /// Returns the entry back if the key is new and the map is full.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Result<Option<V>, (K, V)> {
+        self.inner.checked_insert(k, v)
+    }
+}
+
+impl<K, V, const N: usize> Deref for ArrayBinaryMap<K, V, N> {
+    type Target = BinaryMap<K, V, ArrayVec<(K, V), N>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K, V, const N: usize> DerefMut for ArrayBinaryMap<K, V, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<K, V, const N: usize> From<BinaryMap<K, V, ArrayVec<(K, V), N>>> for ArrayBinaryMap<K, V, N> {
+    #[inline]
+    fn from(inner: BinaryMap<K, V, ArrayVec<(K, V), N>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for ArrayBinaryMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.inner.into_iter())
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a ArrayBinaryMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a mut ArrayBinaryMap<K, V, N> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+impl<K, V, const N: usize> Default for ArrayBinaryMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize> Debug for ArrayBinaryMap<K, V, N> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V, const N: usize, const M: usize> TryFrom<[(K, V); M]> for ArrayBinaryMap<K, V, N> {
+    type Error = (K, V);
+
+    /// Builds a map out of the entries, returning the first one that doesn't fit.
+    #[inline]
+    fn try_from(value: [(K, V); M]) -> Result<Self, Self::Error> {
+        let mut result = Self::new();
+        result.checked_extend(value)?;
+        Ok(result)
+    }
+}
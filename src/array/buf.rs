@@ -0,0 +1,356 @@
+use core::{
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Bound, RangeBounds},
+    ptr,
+    slice,
+};
+
+/// A vector with a fixed capacity of `N` elements, stored inline.
+///
+/// This is the storage of [`ArrayMap`](super::ArrayMap) and [`ArrayBinaryMap`](super::ArrayBinaryMap),
+/// and can back a [`VecMap`](crate::vec::VecMap) or a [`BinaryMap`](crate::binary::BinaryMap).
+pub struct ArrayVec<T, const N: usize> {
+    len: usize,
+    data: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self { len: 0, data: [const { MaybeUninit::uninit() }; N] }
+    }
+
+    #[inline]
+    pub const fn len (&self) -> usize {
+        return self.len
+    }
+
+    #[inline]
+    pub const fn is_empty (&self) -> bool {
+        return self.len == 0
+    }
+
+    #[inline]
+    pub const fn is_full (&self) -> bool {
+        return self.len == N
+    }
+
+    #[inline]
+    pub fn as_slice (&self) -> &[T] {
+        return unsafe { slice::from_raw_parts(self.data.as_ptr().cast(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice (&mut self) -> &mut [T] {
+        return unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_ptr (&mut self) -> *mut T {
+        return self.data.as_mut_ptr().cast()
+    }
+
+    /// # Safety
+    /// `len` must not exceed `N`, and the first `len` values must be initialized.
+    #[inline]
+    pub unsafe fn set_len (&mut self, len: usize) {
+        debug_assert!(len <= N);
+        self.len = len
+    }
+
+    /// Inserts the value at `idx`, shifting the following ones to the right, or returns it back if the buffer is full.
+    #[inline]
+    pub fn insert (&mut self, idx: usize, value: T) -> Result<(), T> {
+        assert!(idx <= self.len, "insertion index (is {idx}) should be <= len (is {})", self.len);
+        if self.is_full() {
+            return Err(value)
+        }
+
+        unsafe {
+            let ptr = self.data.as_mut_ptr().add(idx);
+            ptr::copy(ptr, ptr.add(1), self.len - idx);
+            ptr.write(MaybeUninit::new(value));
+        }
+        self.len += 1;
+        return Ok(())
+    }
+
+    #[inline]
+    pub fn push (&mut self, value: T) -> Result<(), T> {
+        return self.insert(self.len, value)
+    }
+
+    #[inline]
+    pub fn pop (&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None
+        }
+
+        self.len -= 1;
+        return unsafe { Some(self.data.as_ptr().add(self.len).read().assume_init()) }
+    }
+
+    /// Removes the value at `idx`, shifting the following ones to the left.
+    #[inline]
+    pub fn remove (&mut self, idx: usize) -> T {
+        assert!(idx < self.len, "removal index (is {idx}) should be < len (is {})", self.len);
+        unsafe {
+            let ptr = self.data.as_mut_ptr().add(idx);
+            let value = ptr.read().assume_init();
+            ptr::copy(ptr.add(1), ptr, self.len - idx - 1);
+            self.len -= 1;
+            return value
+        }
+    }
+
+    /// Removes the value at `idx`, replacing it with the last one.
+    #[inline]
+    pub fn swap_remove (&mut self, idx: usize) -> T {
+        assert!(idx < self.len, "swap_remove index (is {idx}) should be < len (is {})", self.len);
+        let last = self.len - 1;
+        self.as_mut_slice().swap(idx, last);
+        return unsafe { self.pop().unwrap_unchecked() }
+    }
+
+    #[inline]
+    pub fn truncate (&mut self, len: usize) {
+        if len >= self.len {
+            return
+        }
+
+        let tail = ptr::slice_from_raw_parts_mut(self.data[len..].as_mut_ptr().cast::<T>(), self.len - len);
+        self.len = len;
+        unsafe { ptr::drop_in_place(tail) }
+    }
+
+    #[inline]
+    pub fn clear (&mut self) {
+        self.truncate(0)
+    }
+
+    /// Keeps the values that match the predicate, preserving their order.
+    #[inline]
+    pub fn retain<F: FnMut(&mut T) -> bool> (&mut self, mut f: F) {
+        let slice = self.as_mut_slice();
+        let len = slice.len();
+
+        let mut removed = 0;
+        for i in 0..len {
+            if !f(&mut slice[i]) {
+                removed += 1;
+            } else if removed > 0 {
+                slice.swap(i - removed, i);
+            }
+        }
+        self.truncate(len - removed)
+    }
+
+    /// Removes the values in the range, returning them in order.
+    /// The ones left unconsumed are dropped along with the iterator.
+    #[inline]
+    pub fn drain<R: RangeBounds<usize>> (&mut self, range: R) -> Drain<'_, T, N> {
+        let start = match range.start_bound() {
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&x) => x + 1,
+            Bound::Excluded(&x) => x,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len, "drain range {start}..{end} out of bounds for length {}", self.len);
+
+        // Forget about the drained values and the tail until the iterator is dropped, in case it's leaked
+        let tail_len = self.len - end;
+        self.len = start;
+        return Drain { vec: self, start, end, tail_start: end, tail_len }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for ArrayVec<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for ArrayVec<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut result = Self::new();
+        for value in self.as_slice() {
+            let _ = result.push(value.clone());
+        }
+        return result
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let this = ManuallyDrop::new(self);
+        return IntoIter { start: 0, end: this.len, data: unsafe { ptr::read(&this.data) } }
+    }
+}
+
+/// An owning iterator over the values of an [`ArrayVec`].
+pub struct IntoIter<T, const N: usize> {
+    start: usize,
+    end: usize,
+    data: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    #[inline]
+    pub fn as_slice (&self) -> &[T] {
+        return unsafe { slice::from_raw_parts(self.data.as_ptr().add(self.start).cast(), self.end - self.start) }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None
+        }
+
+        self.start += 1;
+        return unsafe { Some(self.data.as_ptr().add(self.start - 1).read().assume_init()) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        return (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None
+        }
+
+        self.end -= 1;
+        return unsafe { Some(self.data.as_ptr().add(self.end).read().assume_init()) }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        let rest = ptr::slice_from_raw_parts_mut(self.data[self.start..self.end].as_mut_ptr().cast::<T>(), self.end - self.start);
+        unsafe { ptr::drop_in_place(rest) }
+    }
+}
+
+/// A draining iterator over a range of an [`ArrayVec`], returned by [`ArrayVec::drain`].
+pub struct Drain<'a, T, const N: usize> {
+    vec: &'a mut ArrayVec<T, N>,
+    start: usize,
+    end: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<T, const N: usize> Drain<'_, T, N> {
+    #[inline]
+    pub fn as_slice (&self) -> &[T] {
+        return unsafe { slice::from_raw_parts(self.vec.data.as_ptr().add(self.start).cast(), self.end - self.start) }
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Drain<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None
+        }
+
+        self.start += 1;
+        return unsafe { Some(self.vec.data.as_ptr().add(self.start - 1).read().assume_init()) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        return (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None
+        }
+
+        self.end -= 1;
+        return unsafe { Some(self.vec.data.as_ptr().add(self.end).read().assume_init()) }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let data = self.vec.data.as_mut_ptr();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(data.add(self.start).cast::<T>(), self.end - self.start));
+            ptr::copy(data.add(self.tail_start), data.add(self.vec.len), self.tail_len);
+        }
+        self.vec.len += self.tail_len;
+    }
+}
@@ -0,0 +1,20 @@
+use super::buf;
+
+pub type Iter<'a, K, V> = crate::slice::Iter<'a, K, V>;
+pub type IterMut<'a, K, V> = crate::slice::IterMut<'a, K, V>;
+pub type Keys<'a, K, V> = crate::slice::Keys<'a, K, V>;
+pub type Values<'a, K, V> = crate::slice::Values<'a, K, V>;
+pub type ValuesMut<'a, K, V> = crate::slice::ValuesMut<'a, K, V>;
+
+#[repr(transparent)]
+pub struct IntoIter<K, V, const N: usize> (pub(super) buf::IntoIter<(K, V), N>);
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug, const N: usize> core::fmt::Debug for IntoIter<K, V, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.0.as_slice()).finish()
+    }
+}
+
+forward_iter! {
+    impl[K, V, const N: usize] IntoIter<K, V, N> => (K, V);
+}
@@ -0,0 +1,134 @@
+use super::{buf::ArrayVec, *};
+use crate::vec::{IntoKeys, IntoValues, VecMap};
+use core::{fmt::Debug, ops::{Deref, DerefMut}};
+
+/// A map with a fixed capacity of `N` entries, stored inline without any heap allocation.
+/// Lookups are linear, like in [`VecMap`].
+///
+/// It's a [`VecMap`] backed by an [`ArrayVec`], which it dereferences to for everything besides its construction,
+/// insertions and the methods that consume it. Insertions return the entry back if the key is new and the map is full.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArrayMap<K, V, const N: usize> {
+    inner: VecMap<K, V, ArrayVec<(K, V), N>>,
+}
+
+impl<K, V, const N: usize> ArrayMap<K, V, N> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self { inner: unsafe { VecMap::from_vec_unchecked(ArrayVec::new()) } }
+    }
+
+    #[inline]
+    pub const fn capacity (&self) -> usize {
+        return N
+    }
+
+    #[inline]
+    pub fn is_full (&self) -> bool {
+        return self.inner.storage().is_full()
+    }
+
+    #[inline]
+    pub fn into_inner (self) -> VecMap<K, V, ArrayVec<(K, V), N>> {
+        return self.inner
+    }
+
+    #[inline]
+    pub fn into_keys (self) -> IntoKeys<K, V, ArrayVec<(K, V), N>> {
+        return self.inner.into_keys()
+    }
+
+    #[inline]
+    pub fn into_values (self) -> IntoValues<K, V, ArrayVec<(K, V), N>> {
+        return self.inner.into_values()
+    }
+}
+
+impl<K: Eq, V, const N: usize> ArrayMap<K, V, N> {
+    /// Inserts the entry, returning the previous value of the key.
+    /// Returns the entry back if the key is new and the map is full.
+    #[inline]
+    pub fn insert (&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        return self.inner.checked_insert(key, value)
+    }
+}
+
+impl<K, V, const N: usize> Deref for ArrayMap<K, V, N> {
+    type Target = VecMap<K, V, ArrayVec<(K, V), N>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K, V, const N: usize> DerefMut for ArrayMap<K, V, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<K, V, const N: usize> From<VecMap<K, V, ArrayVec<(K, V), N>>> for ArrayMap<K, V, N> {
+    #[inline]
+    fn from(inner: VecMap<K, V, ArrayVec<(K, V), N>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for ArrayMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.inner.into_iter())
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a ArrayMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a mut ArrayMap<K, V, N> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+impl<K, V, const N: usize> Default for ArrayMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize> Debug for ArrayMap<K, V, N> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Eq, V, const N: usize, const M: usize> TryFrom<[(K, V); M]> for ArrayMap<K, V, N> {
+    type Error = (K, V);
+
+    /// Builds a map out of the entries, returning the first one that doesn't fit.
+    #[inline]
+    fn try_from(value: [(K, V); M]) -> Result<Self, Self::Error> {
+        let mut result = Self::new();
+        result.checked_extend(value)?;
+        return Ok(result)
+    }
+}
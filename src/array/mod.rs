@@ -0,0 +1,5 @@
+//! Maps with a fixed capacity, which store their entries inline and never allocate.
+
+flat_mod! { iter, map, binary }
+pub(crate) mod buf;
+pub use buf::ArrayVec;
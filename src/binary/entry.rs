@@ -1,112 +1,252 @@
+use core::marker::PhantomData;
+use crate::{storage::MapStorage, vec::sealed::Occupied};
 #[cfg(feature = "alloc")]
-use core::alloc::*;
-#[cfg(feature = "alloc")]
-use alloc::alloc::*;
-use alloc::vec::Vec;
+use crate::{heap::{collections::TryReserveError, vec::Vec}, storage::HeapStorage};
 
-#[cfg(feature = "alloc")]
 #[derive(Debug)]
-pub enum Entry<'a, K, V, A: Allocator = Global> {
-    Occupied (OcuppiedEntry<'a, K, V, A>),
-    Vacant (VacantEntry<'a, K, V, A>)
+pub enum Entry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S> {
+    Occupied (OccupiedEntry<'a, K, V, S>),
+    Vacant (VacantEntry<'a, K, V, S>)
 }
 
-#[cfg(not(feature = "alloc"))]
-#[derive(Debug)]
-pub enum Entry<'a, K, V> {
-    Occupied (OcuppiedEntry<'a, K, V>),
-    Vacant (VacantEntry<'a, K, V>)
-}
+#[deprecated(note = "renamed to `OccupiedEntry`")]
+pub type OcuppiedEntry<'a, K, V, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S> = OccupiedEntry<'a, K, V, S>;
 
+/// An entry of a key present in the map.
+///
+/// `Q` is the key the entry was looked up with by [`entry`](super::BinaryMap::entry), which the `replace_*` methods
+/// swap in. The entries that weren't looked up by key, like the ones returned by `insert_entry` or
+/// [`first_entry`](super::BinaryMap::first_entry), hold `()` instead.
 #[derive(Debug)]
-pub struct OcuppiedEntry<'a, K, V, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
-    pub(super) parent: &'a mut Vec<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    pub(super) parent: &'a mut Vec<(K, V)>,
-    pub(super) idx: usize
+pub struct OccupiedEntry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S, Q = K> {
+    pub(super) parent: &'a mut S,
+    pub(super) idx: usize,
+    pub(super) key: Q,
+    pub(super) _marker: PhantomData<(K, V)>,
 }
 
 #[derive(Debug)]
-pub struct VacantEntry<'a, K, V, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
-    pub(super) parent: &'a mut Vec<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    pub(super) parent: &'a mut Vec<(K, V)>,
+pub struct VacantEntry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S> {
+    pub(super) parent: &'a mut S,
     pub(super) idx: usize,
     pub(super) key: K,
+    pub(super) _marker: PhantomData<V>,
 }
 
-macro_rules! impl_all {
-    ($name:ident => { $($t:tt)* }) => {
-        #[cfg(feature = "alloc")]
-        impl<'a, K, V, A: Allocator> $name<'a, K, V, A> {
-            $($t)*
+impl<'a, K, V, S: MapStorage<K, V>> Entry<'a, K, V, S> {
+    #[inline]
+    pub fn key (&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
         }
+    }
 
-        #[cfg(not(feature = "alloc"))]
-        impl<'a, K, V> $name<'a, K, V> {
-            $($t)*
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)> (mut self, f: F) -> Self {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(entry.get_mut())
         }
-    };
-}
+        self
+    }
 
-impl_all! {
-    OcuppiedEntry => {
-        #[inline]
-        pub fn key (&self) -> &K {
-            unsafe { &self.parent.get_unchecked(self.idx).0 }
+    /// Same as `or_insert`, but returns the entry back if the key is new and the storage is full.
+    #[inline]
+    pub fn checked_or_insert (self, default: V) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.checked_insert(default),
         }
-        
-        #[inline]
-        pub fn remove_entry (self) -> (K, V) {
-            self.parent.remove(self.idx)
+    }
+
+    #[inline]
+    pub fn checked_or_insert_with<F: FnOnce() -> V> (self, default: F) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.checked_insert(default()),
         }
-        
-        #[inline]
-        pub fn get (&self) -> &V {
-            unsafe { &self.parent.get_unchecked(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn checked_or_insert_with_key<F: FnOnce(&K) -> V> (self, default: F) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.checked_insert(value)
+            }
         }
-        
-        #[inline]
-        pub fn get_mut (&mut self) -> &mut V {
-            unsafe { &mut self.parent.get_unchecked_mut(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn checked_or_default (self) -> Result<&'a mut V, (K, V)> where V: Default {
+        self.checked_or_insert_with(V::default)
+    }
+}
+
+#[docfg::docfg(feature = "alloc")]
+impl<'a, K, V, S: HeapStorage<K, V>> Entry<'a, K, V, S> {
+    #[inline]
+    pub fn or_insert (self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
         }
-        
-        #[inline]
-        pub fn into_mut (self) -> &'a mut V {
-            unsafe { &mut self.parent.get_unchecked_mut(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V> (self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
         }
-        
-        #[inline]
-        pub fn insert (&mut self, value: V) -> V {
-            unsafe { core::mem::replace(&mut self.parent.get_unchecked_mut(self.idx).1, value) }
+    }
+
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V> (self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
         }
-        
-        #[inline]
-        pub fn remove (self) -> V {
-            let (_, v) = self.remove_entry();
-            return v
+    }
+
+    #[inline]
+    pub fn or_default (self) -> &'a mut V where V: Default {
+        self.or_insert_with(V::default)
+    }
+
+    /// Sets the value of the entry, and returns an [`OccupiedEntry`] pointing to it.
+    #[inline]
+    pub fn insert_entry (self, value: V) -> OccupiedEntry<'a, K, V, S, ()> {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                OccupiedEntry { parent: entry.parent, idx: entry.idx, key: (), _marker: PhantomData }
+            },
+            Entry::Vacant(entry) => entry.insert_entry(value),
         }
     }
 }
 
-impl_all! {
-    VacantEntry => {
-        #[inline]
-        pub fn key (&self) -> &K {
-            &self.key
-        }
-        
-        #[inline]
-        pub fn into_key (self) -> K {
-            self.key
-        }
-        
-        #[inline]
-        pub fn insert (self, value: V) -> &'a mut V {
-            self.parent.insert(self.idx, (self.key, value));
-            return unsafe { &mut self.parent.get_unchecked_mut(self.idx).1 }
+impl<'a, K, V, S: MapStorage<K, V>, Q> OccupiedEntry<'a, K, V, S, Q> {
+    /// Returns the position of the entry in the map.
+    #[inline]
+    pub fn index (&self) -> usize {
+        self.idx
+    }
+
+    #[inline]
+    pub fn key (&self) -> &K {
+        unsafe { &self.parent.as_slice().get_unchecked(self.idx).0 }
+    }
+
+    #[inline]
+    pub fn remove_entry (self) -> (K, V) {
+        self.parent.remove(self.idx)
+    }
+
+    #[inline]
+    pub fn get (&self) -> &V {
+        unsafe { &self.parent.as_slice().get_unchecked(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn get_mut (&mut self) -> &mut V {
+        unsafe { &mut self.parent.as_mut_slice().get_unchecked_mut(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn into_mut (self) -> &'a mut V {
+        unsafe { &mut self.parent.as_mut_slice().get_unchecked_mut(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn insert (&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove (self) -> V {
+        let (_, v) = self.remove_entry();
+        return v
+    }
+}
+
+impl<'a, K, V, S: MapStorage<K, V>> OccupiedEntry<'a, K, V, S> {
+    /// Replaces the entry's key with the one it was looked up with, and its value with `value`,
+    /// returning the previous key and value.
+    #[inline]
+    pub fn replace_entry (self, value: V) -> (K, V) {
+        let entry = unsafe { self.parent.as_mut_slice().get_unchecked_mut(self.idx) };
+        return core::mem::replace(entry, (self.key, value))
+    }
+
+    /// Replaces the entry's key with the one it was looked up with, returning the previous key.
+    #[inline]
+    pub fn replace_key (self) -> K {
+        let entry = unsafe { self.parent.as_mut_slice().get_unchecked_mut(self.idx) };
+        return core::mem::replace(&mut entry.0, self.key)
+    }
+}
+
+impl<'a, K, V, S: MapStorage<K, V>> VacantEntry<'a, K, V, S> {
+    #[inline]
+    pub fn key (&self) -> &K {
+        &self.key
+    }
+
+    #[inline]
+    pub fn into_key (self) -> K {
+        self.key
+    }
+
+    /// Inserts the entry in its sorted position, or returns it back if the storage is full.
+    #[inline]
+    pub fn checked_insert (self, value: V) -> Result<&'a mut V, (K, V)> {
+        self.parent.insert(self.idx, (self.key, value))?;
+        return unsafe { Ok(&mut self.parent.as_mut_slice().get_unchecked_mut(self.idx).1) }
+    }
+}
+
+#[docfg::docfg(feature = "alloc")]
+impl<'a, K, V, S: HeapStorage<K, V>> VacantEntry<'a, K, V, S> {
+    #[inline]
+    pub fn insert (self, value: V) -> &'a mut V {
+        self.parent.insert_growing(self.idx, (self.key, value));
+        return unsafe { &mut self.parent.as_mut_slice().get_unchecked_mut(self.idx).1 }
+    }
+
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the entry, handing the value back alongside it.
+    #[inline]
+    pub fn try_insert (self, value: V) -> Result<&'a mut V, (TryReserveError, V)> {
+        if let Err(e) = self.parent.try_reserve(1) {
+            return Err((e, value))
         }
+        return Ok(self.insert(value))
+    }
+
+    #[inline]
+    pub fn insert_entry (self, value: V) -> OccupiedEntry<'a, K, V, S, ()> {
+        self.parent.insert_growing(self.idx, (self.key, value));
+        return OccupiedEntry { parent: self.parent, idx: self.idx, key: (), _marker: PhantomData }
+    }
+}
+
+impl<'a, K, V, S: MapStorage<K, V>, Q> Occupied for OccupiedEntry<'a, K, V, S, Q> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn key (&self) -> &K {
+        OccupiedEntry::key(self)
+    }
+
+    #[inline]
+    fn get (&self) -> &V {
+        OccupiedEntry::get(self)
     }
-}
\ No newline at end of file
+}
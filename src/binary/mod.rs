@@ -1,41 +1,35 @@
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
 #[cfg(feature = "alloc")]
-use alloc::alloc::*;
-use alloc::{boxed::Box, vec::Vec};
-#[cfg(feature = "alloc")]
-use core::alloc::*;
+use crate::{
+    heap::{boxed::Box, collections::TryReserveError, vec::Vec},
+    storage::HeapStorage,
+    vec::{dedup, DuplicateKeysError, DuplicatePolicy},
+};
 use core::{
     borrow::Borrow,
-    ops::{Index, IndexMut},
+    cmp::Ordering,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
 };
 use docfg::docfg;
+use crate::{
+    storage::MapStorage,
+    vec::{many, storage_copy, storage_entry, Compact},
+};
 
-macro_rules! impl_all {
-    ($(where $($trait:path),+ =>)? { $($t:tt)* }) => {
-        #[cfg(feature = "alloc")]
-        impl<K, V, A: Allocator> BinaryMap<K, V, A> $(where K: $($trait+)*)? {
-            $($t)*
-        }
-
-        #[cfg(not(feature = "alloc"))]
-        impl<K, V> BinaryMap<K, V> $(where K: $($trait+)*)? {
-            $($t)*
-        }
-    };
-}
+flat_mod! { entry, ops }
 
-flat_mod! { entry }
+pub use crate::vec::{ExtractIf, IntoKeys, IntoValues, OccupiedError};
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
+    if #[cfg(feature = "allocator_api")] {
         pub type IntoIter<K, V, A> = crate::vec::IntoIter<K, V, A>;
-        pub type IntoKeys<K, V, A> = crate::vec::IntoKeys<K, V, A>;
-        pub type IntoValues<K, V, A> = crate::vec::IntoValues<K, V, A>;
-        pub type Drain<'a, K, V, A> = alloc::vec::Drain<'a, (K, V), A>;
-    } else {
+        pub type Drain<'a, K, V, A> = crate::heap::vec::Drain<'a, (K, V), A>;
+    } else if #[cfg(feature = "alloc")] {
         pub type IntoIter<K, V> = crate::vec::IntoIter<K, V>;
-        pub type IntoKeys<K, V> = crate::vec::IntoKeys<K, V>;
-        pub type IntoValues<K, V> = crate::vec::IntoValues<K, V>;
-        pub type Drain<'a, K, V> = alloc::vec::Drain<'a, (K, V)>;
+        pub type Drain<'a, K, V> = crate::heap::vec::Drain<'a, (K, V)>;
     }
 }
 
@@ -44,20 +38,47 @@ pub type IterMut<'a, K, V> = crate::vec::IterMut<'a, K, V>;
 pub type Keys<'a, K, V> = crate::vec::Keys<'a, K, V>;
 pub type Values<'a, K, V> = crate::vec::Values<'a, K, V>;
 
-#[derive(Debug, Clone)]
-pub struct BinaryMap<K, V, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
-    inner: Vec<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    inner: Vec<(K, V)>,
+/// Compares a key against a range, as expected by [`BinaryMap::range_by`]: keys before the start of the range
+/// are [`Less`](Ordering::Less), keys after its end are [`Greater`](Ordering::Greater), and keys inside it are [`Equal`](Ordering::Equal).
+#[inline]
+pub(crate) fn cmp_to_range<Q: ?Sized + Ord, R: RangeBounds<Q>>(key: &Q, range: &R) -> Ordering {
+    let before = match range.start_bound() {
+        Bound::Included(start) => key < start,
+        Bound::Excluded(start) => key <= start,
+        Bound::Unbounded => false,
+    };
+    if before {
+        return Ordering::Less;
+    }
+
+    let after = match range.end_bound() {
+        Bound::Included(end) => key > end,
+        Bound::Excluded(end) => key >= end,
+        Bound::Unbounded => false,
+    };
+    match after {
+        true => Ordering::Greater,
+        false => Ordering::Equal,
+    }
+}
+
+/// A map that keeps its entries sorted by key in a [`MapStorage`], and finds them through binary search.
+///
+/// As with [`VecMap`](crate::vec::VecMap), the storage is a `Vec` by default, and the methods that grow the map on
+/// demand need a [`HeapStorage`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BinaryMap<K, V, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S> {
+    inner: S,
+    _marker: PhantomData<(K, V)>,
 }
 
-#[docfg(feature = "alloc")]
-impl<K, V, A: Allocator> BinaryMap<K, V, A> {
+#[docfg(feature = "allocator_api")]
+impl<K, V, A: Allocator> BinaryMap<K, V, Vec<(K, V), A>> {
     #[inline]
     pub const fn new_in(alloc: A) -> Self {
         Self {
             inner: Vec::new_in(alloc),
+            _marker: PhantomData,
         }
     }
 
@@ -65,167 +86,112 @@ impl<K, V, A: Allocator> BinaryMap<K, V, A> {
     pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         Self {
             inner: Vec::with_capacity_in(capacity, alloc),
+            _marker: PhantomData,
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<K, V> BinaryMap<K, V> {
     #[inline]
     pub const fn new() -> Self {
-        Self { inner: Vec::new() }
+        Self {
+            inner: Vec::new(),
+            _marker: PhantomData,
+        }
     }
 
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: Vec::with_capacity(capacity),
+            _marker: PhantomData,
         }
     }
 }
 
-impl_all! {{
+impl<K, V, S: MapStorage<K, V>> BinaryMap<K, V, S> {
+    /// Builds a map out of a storage, or returns it back if its keys aren't sorted and unique.
+    /// Unlike [`from_vec`](Self::from_vec), it's available without the `alloc` feature.
     #[inline]
-    pub fn len(&self) -> usize {
-        self.inner.len()
+    pub fn from_storage(storage: S) -> Result<Self, S>
+    where
+        K: Ord,
+    {
+        if !storage.as_slice().windows(2).all(|w| w[0].0 < w[1].0) {
+            return Err(storage);
+        }
+        Ok(Self {
+            inner: storage,
+            _marker: PhantomData,
+        })
     }
 
+    /// # Safety
+    /// The keys must be sorted and unique.
     #[inline]
-    pub fn capacity(&self) -> usize {
-        self.inner.capacity()
+    pub const unsafe fn from_vec_unchecked(vec: S) -> Self {
+        return Self {
+            inner: vec,
+            _marker: PhantomData,
+        };
     }
 
     #[inline]
-    pub fn clear(&mut self) {
-        self.inner.clear()
+    pub fn storage(&self) -> &S {
+        &self.inner
     }
 
     #[inline]
-    pub fn contains_key<Q>(&self, k: &Q) -> bool
-    where
-        K: Borrow<Q>,
-        Q: ?Sized + Ord,
-    {
+    pub fn into_vec(self) -> S {
         self.inner
-            .binary_search_by(|(x, _)| x.borrow().cmp(k))
-            .is_ok()
     }
-}}
 
-#[cfg(feature = "alloc")]
-impl<K, V, A: Allocator> BinaryMap<K, V, A> {
     #[inline]
-    pub fn drain(&mut self) -> Drain<'_, K, V, A> {
-        self.inner.drain(..)
+    pub fn len(&self) -> usize {
+        self.inner.len()
     }
-}
 
-#[cfg(not(feature = "alloc"))]
-impl<K, V> BinaryMap<K, V> {
     #[inline]
-    pub fn drain(&mut self) -> Drain<'_, K, V> {
-        self.inner.drain(..)
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
     }
-}
-
-impl_all! {
-    where Ord => {
-        #[inline]
-        pub fn pop(&mut self) -> Option<(K, V)> {
-            self.inner.pop()
-        }
 
-        #[inline]
-        pub fn insert(&mut self, k: K, v: V) -> Option<V> {
-            return match self.entry(k) {
-                Entry::Occupied(mut x) => Some(x.insert(v)),
-                Entry::Vacant(x) => {
-                    x.insert(v);
-                    None
-                }
-            };
-        }
-
-        #[inline]
-        pub fn insert_front(&mut self, k: K, v: V) -> Result<(), (K, V)> {
-            return match self.inner.get_mut(0) {
-                Some(x) if &x.0 < &k => Err((k, v)),
-                _ => {
-                    self.inner.push((k, v));
-                    Ok(())
-                }
-            }
-        }
-
-        #[inline]
-        pub fn insert_back(&mut self, k: K, v: V) -> Result<(), (K, V)> {
-            return match self.inner.last_mut() {
-                Some(x) if &x.0 > &k => Err((k, v)),
-                _ => {
-                    self.inner.push((k, v));
-                    Ok(())
-                }
-            }
-        }
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
 
-        #[inline]
-        pub unsafe fn insert_front_unchecked (&mut self, k: K, v: V) {
-            self.inner.insert(0, (k, v));
-        }
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop)
+    }
 
-        #[inline]
-        pub unsafe fn insert_back_unchecked (&mut self, k: K, v: V) {
-            self.inner.push((k, v));
-        }
+    #[inline]
+    pub fn extract_if<F: FnMut(&K, &mut V) -> bool>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self> {
+        ExtractIf::new(self, pred)
     }
-}
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        impl<K: Ord, V, A: Allocator> BinaryMap<K, V, A> {
-            #[inline]
-            pub fn entry(&mut self, key: K) -> Entry<'_, K, V, A> {
-                match self.inner.binary_search_by(|(x, _)| x.cmp(&key)) {
-                    Ok(idx) => Entry::Occupied(OcuppiedEntry {
-                        parent: &mut self.inner,
-                        idx,
-                    }),
-                    Err(idx) => Entry::Vacant(VacantEntry {
-                        parent: &mut self.inner,
-                        key,
-                        idx,
-                    }),
-                }
-            }
-        }
-    } else {
-        impl<K: Ord, V> BinaryMap<K, V> {
-            #[inline]
-            pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
-                match self.inner.binary_search_by(|(x, _)| x.cmp(&key)) {
-                    Ok(idx) => Entry::Occupied(OcuppiedEntry {
-                        parent: &mut self.inner,
-                        idx,
-                    }),
-                    Err(idx) => Entry::Vacant(VacantEntry {
-                        parent: &mut self.inner,
-                        key,
-                        idx,
-                    }),
-                }
-            }
-        }
+    #[inline]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.inner
+            .as_slice()
+            .binary_search_by(|(x, _)| x.borrow().cmp(k))
+            .is_ok()
     }
-}
 
-impl_all! {{
     #[inline]
     pub fn get<Q>(&self, k: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
         Q: ?Sized + Ord,
     {
-        match self.inner.binary_search_by(|(x, _)| x.borrow().cmp(k)) {
-            Ok(idx) => unsafe { Some(&self.inner.get_unchecked(idx).1) },
+        match self.inner.as_slice().binary_search_by(|(x, _)| x.borrow().cmp(k)) {
+            Ok(idx) => unsafe { Some(&self.inner.as_slice().get_unchecked(idx).1) },
             Err(_) => None,
         }
     }
@@ -236,8 +202,8 @@ impl_all! {{
         K: Borrow<Q>,
         Q: ?Sized + Ord,
     {
-        match self.inner.binary_search_by(|(x, _)| x.borrow().cmp(k)) {
-            Ok(idx) => unsafe { Some(&mut self.inner.get_unchecked_mut(idx).1) },
+        match self.inner.as_slice().binary_search_by(|(x, _)| x.borrow().cmp(k)) {
+            Ok(idx) => unsafe { Some(&mut self.inner.as_mut_slice().get_unchecked_mut(idx).1) },
             Err(_) => None,
         }
     }
@@ -248,9 +214,9 @@ impl_all! {{
         K: Borrow<Q>,
         Q: ?Sized + Ord,
     {
-        match self.inner.binary_search_by(|(x, _)| x.borrow().cmp(k)) {
+        match self.inner.as_slice().binary_search_by(|(x, _)| x.borrow().cmp(k)) {
             Ok(idx) => unsafe {
-                let (key, value) = self.inner.get_unchecked(idx);
+                let (key, value) = self.inner.as_slice().get_unchecked(idx);
                 Some((key, value))
             },
             Err(_) => None,
@@ -263,7 +229,7 @@ impl_all! {{
         K: Borrow<Q>,
         Q: ?Sized + Ord,
     {
-        return match self.inner.binary_search_by(|(x, _)| x.borrow().cmp(k)) {
+        return match self.inner.as_slice().binary_search_by(|(x, _)| x.borrow().cmp(k)) {
             Ok(idx) => Some(self.inner.remove(idx).1),
             Err(_) => None,
         };
@@ -275,333 +241,727 @@ impl_all! {{
         K: Borrow<Q>,
         Q: ?Sized + Ord,
     {
-        return match self.inner.binary_search_by(|(x, _)| x.borrow().cmp(k)) {
+        return match self.inner.as_slice().binary_search_by(|(x, _)| x.borrow().cmp(k)) {
             Ok(idx) => Some(self.inner.remove(idx)),
             Err(_) => None,
         };
     }
-}}
 
-impl_all! {{
     #[inline]
-    pub fn iter(&self) -> Iter<'_, K, V> {
-        return crate::vec::Iter(self.inner.iter())
+    pub fn get_index_of<Q>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.inner.as_slice().binary_search_by(|(x, _)| x.borrow().cmp(k)).ok()
     }
 
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
-        return crate::vec::IterMut(self.inner.iter_mut())
+    pub fn get_full<Q>(&self, k: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let idx = self.get_index_of(k)?;
+        let (key, value) = unsafe { self.inner.as_slice().get_unchecked(idx) };
+        Some((idx, key, value))
     }
 
     #[inline]
-    pub fn keys(&self) -> Keys<'_, K, V> {
-        return crate::vec::Keys(self.inner.iter())
+    pub fn get_index(&self, idx: usize) -> Option<(&K, &V)> {
+        let (key, value) = self.inner.as_slice().get(idx)?;
+        Some((key, value))
     }
 
     #[inline]
-    pub fn values(&self) -> Values<'_, K, V> {
-        return crate::vec::Values(self.inner.iter())
+    pub fn get_index_mut(&mut self, idx: usize) -> Option<(&K, &mut V)> {
+        let (key, value) = self.inner.as_mut_slice().get_mut(idx)?;
+        Some((key, value))
     }
-}}
 
-#[cfg(feature = "alloc")]
-impl<K, V, A: Allocator> BinaryMap<K, V, A> {
     #[inline]
-    pub fn from_vec(mut vec: Vec<(K, V), A>) -> Self
+    pub fn remove_index(&mut self, idx: usize) -> Option<(K, V)> {
+        if idx >= self.inner.len() {
+            return None;
+        }
+        Some(self.inner.remove(idx))
+    }
+
+    /// Returns references to the values of all the keys, or `None` if any of them is missing.
+    #[inline]
+    pub fn get_many<Q, const N: usize>(&self, keys: [&Q; N]) -> Option<[&V; N]>
     where
-        K: Ord,
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
     {
-        vec.sort_unstable_by(|(x, _), (y, _)| x.cmp(y));
-        return unsafe { Self::from_vec_unchecked(vec) };
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        Some(many::values(self.inner.as_slice(), indices))
     }
 
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing
+    /// or if two of them refer to the same entry.
     #[inline]
-    pub fn from_box(bx: Box<[(K, V)], A>) -> Self
+    pub fn get_many_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> Option<[&mut V; N]>
     where
-        K: Ord,
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
     {
-        Self::from_vec(bx.into_vec())
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        if !many::is_disjoint(&indices) {
+            return None;
+        }
+        unsafe { Some(many::values_mut(self.inner.as_mut_slice(), indices)) }
     }
 
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing.
+    ///
+    /// # Safety
+    /// No two keys may refer to the same entry.
     #[inline]
-    pub unsafe fn from_vec_unchecked(vec: Vec<(K, V), A>) -> Self {
-        return Self { inner: vec };
+    pub unsafe fn get_many_unchecked_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> Option<[&mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        debug_assert!(many::is_disjoint(&indices));
+        Some(many::values_mut(self.inner.as_mut_slice(), indices))
     }
 
+    /// Binary searches the map with a comparator on the keys, returning the matching entry along with its index.
+    /// The comparator must be consistent with the ordering of the keys, as in [`slice::binary_search_by`].
     #[inline]
-    pub unsafe fn from_box_unchecked(bx: Box<[(K, V)], A>) -> Self {
-        return Self::from_vec_unchecked(bx.into_vec());
+    pub fn search_by<F: FnMut(&K) -> Ordering>(&self, mut f: F) -> Option<(usize, &K, &V)> {
+        let idx = self.inner.as_slice().binary_search_by(|(k, _)| f(k)).ok()?;
+        let (key, value) = unsafe { self.inner.as_slice().get_unchecked(idx) };
+        Some((idx, key, value))
     }
 
+    /// Binary searches the map with a comparator on the keys, returning the matching entry along with its index.
+    /// The comparator must be consistent with the ordering of the keys, as in [`slice::binary_search_by`].
     #[inline]
-    pub fn into_vec(self) -> Vec<(K, V), A> {
-        self.inner
+    pub fn search_by_mut<F: FnMut(&K) -> Ordering>(&mut self, mut f: F) -> Option<(usize, &K, &mut V)> {
+        let idx = self.inner.as_slice().binary_search_by(|(k, _)| f(k)).ok()?;
+        let (key, value) = unsafe { self.inner.as_mut_slice().get_unchecked_mut(idx) };
+        Some((idx, key, value))
     }
 
+    /// Returns all the entries for which the comparator returns [`Ordering::Equal`], along with the range of their indices.
+    /// The comparator must be consistent with the ordering of the keys, as in [`slice::binary_search_by`].
     #[inline]
-    pub fn into_box(self) -> Box<[(K, V)], A> {
-        self.inner.into_boxed_slice()
+    pub fn range_by<F: FnMut(&K) -> Ordering>(&self, mut f: F) -> (Range<usize>, Iter<'_, K, V>) {
+        let start = self.inner.as_slice().partition_point(|(k, _)| f(k) == Ordering::Less);
+        let end = start + self.inner.as_slice()[start..].partition_point(|(k, _)| f(k) != Ordering::Greater);
+        (start..end, crate::vec::Iter(self.inner.as_slice()[start..end].iter()))
     }
 
+    /// Returns the indices of the entries whose keys fall inside the range.
     #[inline]
-    pub fn into_keys(self) -> IntoKeys<K, V, A> {
-        return crate::vec::IntoKeys(self.inner.into_iter());
+    fn range_indices<Q, R>(&self, range: &R) -> Range<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
+    {
+        let start = self.inner.as_slice().partition_point(|(k, _)| cmp_to_range(k.borrow(), range) == Ordering::Less);
+        let end = start + self.inner.as_slice()[start..].partition_point(|(k, _)| cmp_to_range(k.borrow(), range) == Ordering::Equal);
+        start..end
     }
 
+    /// Iterates the entries whose keys fall inside the range, in ascending order.
     #[inline]
-    pub fn into_values(self) -> IntoValues<K, V, A> {
-        return crate::vec::IntoValues(self.inner.into_iter());
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
+    {
+        let range = self.range_indices(&range);
+        crate::vec::Iter(self.inner.as_slice()[range].iter())
     }
-}
 
-#[cfg(not(feature = "alloc"))]
-impl<K, V> BinaryMap<K, V> {
+    /// Mutably iterates the entries whose keys fall inside the range, in ascending order.
     #[inline]
-    pub fn from_vec(mut vec: Vec<(K, V)>) -> Self
+    pub fn range_mut<Q, R>(&mut self, range: R) -> IterMut<'_, K, V>
     where
-        K: Ord,
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
     {
-        vec.sort_unstable_by(|(x, _), (y, _)| x.cmp(y));
-        return unsafe { Self::from_vec_unchecked(vec) };
+        let range = self.range_indices(&range);
+        crate::vec::IterMut(self.inner.as_mut_slice()[range].iter_mut())
     }
 
+    /// Returns the number of entries whose keys fall inside the range, in O(log n).
     #[inline]
-    pub fn from_box(bx: Box<[(K, V)]>) -> Self
+    pub fn count_range<Q, R>(&self, range: R) -> usize
     where
-        K: Ord,
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
     {
-        Self::from_vec(bx.into_vec())
+        self.range_indices(&range).len()
     }
 
+    /// Returns the entry with the smallest key.
     #[inline]
-    pub unsafe fn from_vec_unchecked(vec: Vec<(K, V)>) -> Self {
-        return Self { inner: vec };
+    pub fn first_key_value (&self) -> Option<(&K, &V)> {
+        let (key, value) = self.inner.as_slice().first()?;
+        return Some((key, value))
     }
 
+    /// Returns the entry with the largest key.
     #[inline]
-    pub unsafe fn from_box_unchecked(bx: Box<[(K, V)]>) -> Self {
-        return Self::from_vec_unchecked(bx.into_vec());
+    pub fn last_key_value (&self) -> Option<(&K, &V)> {
+        let (key, value) = self.inner.as_slice().last()?;
+        return Some((key, value))
     }
 
+    /// Returns the entry with the smallest key.
     #[inline]
-    pub fn into_vec(self) -> Vec<(K, V)> {
-        self.inner
+    pub fn first_entry (&mut self) -> Option<OccupiedEntry<'_, K, V, S, ()>> {
+        if self.inner.is_empty() {
+            return None
+        }
+        return Some(OccupiedEntry { parent: &mut self.inner, idx: 0, key: (), _marker: PhantomData })
     }
 
+    /// Returns the entry with the largest key.
     #[inline]
-    pub fn into_box(self) -> Box<[(K, V)]> {
-        self.inner.into_boxed_slice()
+    pub fn last_entry (&mut self) -> Option<OccupiedEntry<'_, K, V, S, ()>> {
+        let idx = self.inner.len().checked_sub(1)?;
+        return Some(OccupiedEntry { parent: &mut self.inner, idx, key: (), _marker: PhantomData })
     }
 
     #[inline]
-    pub fn into_keys(self) -> IntoKeys<K, V> {
-        return crate::vec::IntoKeys(self.inner.into_iter());
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let idx = self.inner.len().checked_sub(1)?;
+        Some(self.inner.remove(idx))
     }
 
+    /// Removes the entry with the smallest key. This shifts every other entry, taking O(n).
     #[inline]
-    pub fn into_values(self) -> IntoValues<K, V> {
-        return crate::vec::IntoValues(self.inner.into_iter());
+    pub fn pop_first (&mut self) -> Option<(K, V)> {
+        if self.inner.is_empty() {
+            return None
+        }
+        return Some(self.inner.remove(0))
     }
-}
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        impl<K, V, A: Allocator> IntoIterator for BinaryMap<K, V, A> {
-            type Item = (K, V);
-            type IntoIter = IntoIter<K, V, A>;
+    /// Removes the entry with the largest key, same as [`pop`](Self::pop).
+    #[inline]
+    pub fn pop_last (&mut self) -> Option<(K, V)> {
+        return self.pop()
+    }
 
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                self.inner.into_iter()
-            }
-        }
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        return crate::vec::Iter(self.inner.as_slice().iter())
+    }
 
-        impl<'a, K, V, A: Allocator> IntoIterator for &'a BinaryMap<K, V, A> {
-            type Item = (&'a K, &'a V);
-            type IntoIter = Iter<'a, K, V>;
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        return crate::vec::IterMut(self.inner.as_mut_slice().iter_mut())
+    }
 
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                BinaryMap::iter(self)
-            }
-        }
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        return crate::vec::Keys(self.inner.as_slice().iter())
+    }
 
-        impl<'a, K, V, A: Allocator> IntoIterator for &'a mut BinaryMap<K, V, A> {
-            type Item = (&'a K, &'a mut V);
-            type IntoIter = IterMut<'a, K, V>;
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        return crate::vec::Values(self.inner.as_slice().iter())
+    }
 
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                BinaryMap::iter_mut(self)
-            }
-        }
+    #[inline]
+    pub fn values_mut(&mut self) -> crate::vec::ValuesMut<'_, K, V> {
+        return crate::vec::ValuesMut(self.inner.as_mut_slice().iter_mut());
+    }
 
-        impl<K: Ord, V, A: Allocator> Extend<(K, V)> for BinaryMap<K, V, A> {
-            #[inline]
-            fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
-                for (k, v) in iter { let _ = self.insert(k, v); }
-            }
-        }
+    #[inline]
+    pub fn into_keys(self) -> IntoKeys<K, V, S>
+    where
+        S: IntoIterator<Item = (K, V)>,
+    {
+        return IntoKeys(self.inner.into_iter());
+    }
 
-        impl<'a, 'b, K: Ord + Clone, V: Clone, A: Allocator> Extend<(&'a K, &'b V)> for BinaryMap<K, V, A> {
-            #[inline]
-            fn extend<T: IntoIterator<Item = (&'a K, &'b V)>>(&mut self, iter: T) {
-                <Self as Extend<(K, V)>>::extend(self, iter.into_iter().map(|(x, y)| (x.clone(), y.clone())))
-            }
+    #[inline]
+    pub fn into_values(self) -> IntoValues<K, V, S>
+    where
+        S: IntoIterator<Item = (K, V)>,
+    {
+        return IntoValues(self.inner.into_iter());
+    }
+}
+
+impl<K: Ord, V, S: MapStorage<K, V>> BinaryMap<K, V, S> {
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        match self.inner.as_slice().binary_search_by(|(x, _)| x.cmp(&key)) {
+            Ok(idx) => Entry::Occupied(OccupiedEntry {
+                parent: &mut self.inner,
+                idx,
+                key,
+                _marker: PhantomData,
+            }),
+            Err(idx) => Entry::Vacant(VacantEntry {
+                parent: &mut self.inner,
+                key,
+                idx,
+                _marker: PhantomData,
+            }),
         }
+    }
 
-        impl<K: Ord, V, A: Allocator + Default> FromIterator<(K, V)> for BinaryMap<K, V, A> {
-            #[inline]
-            fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-                let mut this = Self::default();
-                this.extend(iter);
-                this
-            }
+    /// Inserts the entry in its sorted position, returning the previous value of the key if it was present.
+    /// If the storage is full, the map is left untouched and the entry is returned back.
+    #[inline]
+    pub fn checked_insert(&mut self, k: K, v: V) -> Result<Option<V>, (K, V)> {
+        match self.entry(k) {
+            Entry::Occupied(mut x) => Ok(Some(x.insert(v))),
+            Entry::Vacant(x) => x.checked_insert(v).map(|_| None),
         }
+    }
 
-        impl<Q: ?Sized + Ord, K: Borrow<Q>, V, A: Allocator> Index<&Q> for BinaryMap<K, V, A> {
-            type Output = V;
+    /// Inserts every entry with [`checked_insert`](Self::checked_insert), stopping at the first one that doesn't fit,
+    /// which is returned back. The entries inserted before it are kept.
+    #[inline]
+    pub fn checked_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), (K, V)> {
+        for (k, v) in iter {
+            self.checked_insert(k, v)?;
+        }
+        Ok(())
+    }
+}
 
-            #[inline]
-            fn index(&self, index: &Q) -> &Self::Output {
-                self.get(index).expect("index not found")
-            }
+#[docfg(feature = "alloc")]
+impl<K, V, S: MapStorage<K, V>> BinaryMap<K, V, S> {
+    /// Builds a map out of a list of entries. If a key appears more than once, its last value is kept,
+    /// as if the entries had been inserted one by one.
+    #[inline]
+    pub fn from_vec(vec: S) -> Self
+    where
+        K: Ord,
+    {
+        match Self::from_vec_with_policy(vec, DuplicatePolicy::KeepLast) {
+            Ok(this) => this,
+            Err(_) => unreachable!(),
         }
+    }
 
-        impl<Q: ?Sized + Ord, K: Borrow<Q>, V, A: Allocator> IndexMut<&Q> for BinaryMap<K, V, A> {
-            #[inline]
-            fn index_mut(&mut self, index: &Q) -> &mut Self::Output {
-                self.get_mut(index).expect("index not found")
+    /// Builds a map out of a list of entries, resolving duplicate keys with `policy`.
+    #[inline]
+    pub fn from_vec_with_policy(mut vec: S, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<S>>
+    where
+        K: Ord,
+    {
+        let entries = vec.as_mut_slice();
+        let len = match policy {
+            DuplicatePolicy::Error => {
+                let duplicates = dedup::duplicates_ord(entries);
+                if !duplicates.is_empty() {
+                    let indices = duplicates.into_iter().map(|(i, _)| i).collect();
+                    return Err(DuplicateKeysError::new(indices, vec));
+                }
+                entries.sort_unstable_by(|(x, _), (y, _)| x.cmp(y));
+                entries.len()
             }
-        }
+            // A stable sort keeps entries with the same key in their original order
+            DuplicatePolicy::KeepFirst => {
+                entries.sort_by(|(x, _), (y, _)| x.cmp(y));
+                dedup::dedup_sorted(entries, false)
+            }
+            DuplicatePolicy::KeepLast => {
+                entries.sort_by(|(x, _), (y, _)| x.cmp(y));
+                dedup::dedup_sorted(entries, true)
+            }
+        };
 
-        impl<K: Ord, V, A: Allocator> From<Vec<(K, V), A>> for BinaryMap<K, V, A> {
-            #[inline]
-            fn from(mut inner: Vec<(K, V), A>) -> Self {
-                inner.sort_unstable_by(|(x, _), (y, _)| x.cmp(y));
-                Self { inner }
+        vec.truncate(len);
+        Ok(unsafe { Self::from_vec_unchecked(vec) })
+    }
+}
+
+#[docfg(feature = "alloc")]
+impl<K, V, S: HeapStorage<K, V>> BinaryMap<K, V, S> {
+    #[inline]
+    pub fn into_box(self) -> S::Boxed {
+        self.inner.into_boxed()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity)
+    }
+
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    #[inline]
+    pub fn drain(&mut self) -> S::Drain<'_> {
+        self.inner.drain(..)
+    }
+
+    /// Removes the entries whose keys fall inside the range, returning them in ascending order.
+    /// Like [`drain`](BinaryMap::drain), the entries are removed even if the iterator isn't consumed.
+    #[inline]
+    pub fn remove_range<Q, R>(&mut self, range: R) -> S::Drain<'_>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
+    {
+        let range = self.range_indices(&range);
+        self.inner.drain(range)
+    }
+
+    /// Splits the map in two, with the entries that match the predicate on the first one,
+    /// and the ones that don't on the second.
+    #[inline]
+    pub fn partition<F: FnMut(&K, &mut V) -> bool>(mut self, mut pred: F) -> (Self, Self)
+    where
+        S::Alloc: Clone,
+    {
+        let mut rhs = S::with_capacity_in(0, self.inner.allocator().clone());
+        for entry in self.extract_if(|k, v| !pred(k, v)) {
+            rhs.insert_growing(rhs.len(), entry);
+        }
+        (
+            self,
+            Self {
+                inner: rhs,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+#[docfg(feature = "alloc")]
+impl<K: Ord, V, S: HeapStorage<K, V>> BinaryMap<K, V, S> {
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        return match self.entry(k) {
+            Entry::Occupied(mut x) => Some(x.insert(v)),
+            Entry::Vacant(x) => {
+                x.insert(v);
+                None
             }
-        }
+        };
+    }
 
-        impl<K: Ord, V, A: Allocator> From<Box<[(K, V)], A>> for BinaryMap<K, V, A> {
-            #[inline]
-            fn from(inner: Box<[(K, V)], A>) -> Self {
-                inner.into_vec().into()
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entry, handing the entry back alongside it.
+    #[inline]
+    pub fn try_insert_alloc(&mut self, k: K, v: V) -> Result<Option<V>, (TryReserveError, K, V)> {
+        match self.inner.as_slice().binary_search_by(|(x, _)| x.cmp(&k)) {
+            Ok(idx) => {
+                let entry = unsafe { self.inner.as_mut_slice().get_unchecked_mut(idx) };
+                Ok(Some(core::mem::replace(&mut entry.1, v)))
+            }
+            Err(idx) => {
+                if let Err(e) = self.inner.try_reserve(1) {
+                    return Err((e, k, v));
+                }
+                self.inner.insert_growing(idx, (k, v));
+                Ok(None)
             }
         }
+    }
 
-        impl<K: Ord, V, A: Allocator + Default, const N: usize> From<[(K, V); N]> for BinaryMap<K, V, A> {
-            #[inline]
-            fn from(inner: [(K, V); N]) -> Self {
-                let mut result = Self::with_capacity_in(N, Default::default());
-                for (k, v) in inner.into_iter() { let _ = result.insert(k, v); }
-                return result
-            }
+    /// Same as [`extend`](Extend::extend), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entries. The entries inserted before the failure are kept.
+    #[inline]
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        let iter = iter.into_iter();
+        self.inner.try_reserve(iter.size_hint().0)?;
+        for (k, v) in iter {
+            self.try_insert_alloc(k, v).map_err(|(e, _, _)| e)?;
         }
+        Ok(())
+    }
 
-        impl<K, V, A: Allocator + Default> Default for BinaryMap<K, V, A> {
-            #[inline]
-            fn default() -> Self {
-                Self::new_in(Default::default())
+    /// Inserts the entry before every other one, or returns it back if its key isn't smaller than all of theirs.
+    #[inline]
+    pub fn insert_front(&mut self, k: K, v: V) -> Result<(), (K, V)> {
+        return match self.inner.as_slice().first() {
+            Some(x) if x.0 <= k => Err((k, v)),
+            _ => {
+                self.inner.insert_growing(0, (k, v));
+                Ok(())
             }
-        }
-    } else {
-        impl<K, V> IntoIterator for BinaryMap<K, V> {
-            type Item = (K, V);
-            type IntoIter = IntoIter<K, V>;
+        };
+    }
 
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                self.inner.into_iter()
+    /// Inserts the entry after every other one, or returns it back if its key isn't larger than all of theirs.
+    #[inline]
+    pub fn insert_back(&mut self, k: K, v: V) -> Result<(), (K, V)> {
+        return match self.inner.as_slice().last() {
+            Some(x) if x.0 >= k => Err((k, v)),
+            _ => {
+                self.inner.insert_growing(self.inner.len(), (k, v));
+                Ok(())
             }
-        }
+        };
+    }
 
-        impl<'a, K, V> IntoIterator for &'a BinaryMap<K, V> {
-            type Item = (&'a K, &'a V);
-            type IntoIter = Iter<'a, K, V>;
+    /// # Safety
+    /// The key must be smaller than every key in the map.
+    #[inline]
+    pub unsafe fn insert_front_unchecked (&mut self, k: K, v: V) {
+        self.inner.insert_growing(0, (k, v));
+    }
 
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                BinaryMap::iter(self)
-            }
+    /// # Safety
+    /// The key must be larger than every key in the map.
+    #[inline]
+    pub unsafe fn insert_back_unchecked (&mut self, k: K, v: V) {
+        self.inner.insert_growing(self.inner.len(), (k, v));
+    }
+
+    /// Inserts the entry if the key isn't already present, returning a reference to the new value.
+    /// Otherwise, the map is left untouched, and an error holding the existing entry and the rejected value is returned.
+    #[inline]
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<OccupiedEntry<'_, K, V, S>>> {
+        match self.entry(key) {
+            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
         }
+    }
+}
 
-        impl<'a, K, V> IntoIterator for &'a mut BinaryMap<K, V> {
-            type Item = (&'a K, &'a mut V);
-            type IntoIter = IterMut<'a, K, V>;
+impl<K, V, S: MapStorage<K, V> + IntoIterator<Item = (K, V)>> IntoIterator for BinaryMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = S::IntoIter;
 
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                BinaryMap::iter_mut(self)
-            }
-        }
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
 
-        impl<K: Ord, V> Extend<(K, V)> for BinaryMap<K, V> {
-            #[inline]
-            fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
-                for (k, v) in iter { let _ = self.insert(k, v); }
-            }
-        }
+impl<'a, K, V, S: MapStorage<K, V>> IntoIterator for &'a BinaryMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
 
-        impl<'a, 'b, K: Ord + Clone, V: Clone> Extend<(&'a K, &'b V)> for BinaryMap<K, V> {
-            #[inline]
-            fn extend<T: IntoIterator<Item = (&'a K, &'b V)>>(&mut self, iter: T) {
-                <Self as Extend<(K, V)>>::extend(self, iter.into_iter().map(|(x, y)| (x.clone(), y.clone())))
-            }
-        }
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        BinaryMap::iter(self)
+    }
+}
 
-        impl<K: Ord, V> FromIterator<(K, V)> for BinaryMap<K, V> {
-            #[inline]
-            fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-                let mut this = Self::default();
-                this.extend(iter);
-                this
-            }
+impl<'a, K, V, S: MapStorage<K, V>> IntoIterator for &'a mut BinaryMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        BinaryMap::iter_mut(self)
+    }
+}
+
+#[docfg(feature = "alloc")]
+impl<K: Ord, V, S: HeapStorage<K, V>> Extend<(K, V)> for BinaryMap<K, V, S> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            let _ = self.insert(k, v);
         }
+    }
+}
 
-        impl<Q: ?Sized + Ord, K: Borrow<Q>, V> Index<&Q> for BinaryMap<K, V> {
-            type Output = V;
+#[docfg(feature = "alloc")]
+impl<'a, 'b, K: Ord + Clone, V: Clone, S: HeapStorage<K, V>> Extend<(&'a K, &'b V)> for BinaryMap<K, V, S> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = (&'a K, &'b V)>>(&mut self, iter: T) {
+        <Self as Extend<(K, V)>>::extend(self, iter.into_iter().map(|(x, y)| (x.clone(), y.clone())))
+    }
+}
 
-            #[inline]
-            fn index(&self, index: &Q) -> &Self::Output {
-                self.get(index).expect("index not found")
-            }
+#[docfg(feature = "alloc")]
+impl<K: Ord, V, S: HeapStorage<K, V>> FromIterator<(K, V)> for BinaryMap<K, V, S>
+where
+    S::Alloc: Default,
+{
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut this = Self {
+            inner: S::with_capacity_in(0, Default::default()),
+            _marker: PhantomData,
+        };
+        this.extend(iter);
+        this
+    }
+}
+
+impl<K, V, S: MapStorage<K, V> + Default> Default for BinaryMap<K, V, S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: S::default(),
+            _marker: PhantomData,
         }
+    }
+}
+
+impl<K: Debug, V: Debug, S: MapStorage<K, V>> Debug for BinaryMap<K, V, S> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BinaryMap").field("inner", &self.inner.as_slice()).finish()
+    }
+}
+
+/// # Safety
+/// Forwards to the storage, whose pointer stays valid up to its capacity.
+unsafe impl<K, V, S: MapStorage<K, V>> Compact<K, V> for BinaryMap<K, V, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        MapStorage::len(&self.inner)
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        MapStorage::set_len(&mut self.inner, len)
+    }
+
+    #[inline]
+    unsafe fn entry(&mut self, idx: usize) -> (*mut K, *mut V) {
+        storage_entry(&mut self.inner, idx)
+    }
+
+    #[inline]
+    unsafe fn copy(&mut self, src: usize, dst: usize, count: usize) {
+        storage_copy(&mut self.inner, src, dst, count)
+    }
+}
+
+impl<Q: ?Sized + Ord, K: Borrow<Q>, V, S: MapStorage<K, V>> Index<&Q> for BinaryMap<K, V, S> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
+    }
+}
+
+impl<Q: ?Sized + Ord, K: Borrow<Q>, V, S: MapStorage<K, V>> IndexMut<&Q> for BinaryMap<K, V, S> {
+    #[inline]
+    fn index_mut(&mut self, index: &Q) -> &mut Self::Output {
+        self.get_mut(index).expect("index not found")
+    }
+}
 
-        impl<Q: ?Sized + Ord, K: Borrow<Q>, V> IndexMut<&Q> for BinaryMap<K, V> {
+/// Implements the conversions from the types of the `alloc` crate into a `Vec`-backed [`BinaryMap`].
+#[cfg(feature = "alloc")]
+macro_rules! impl_vec_conversions {
+    (impl[$($g:tt)*] $vec:ty, $boxed:ty) => {
+        impl<K, V, $($g)*> BinaryMap<K, V, $vec> {
+            /// Builds a map out of a list of entries. If a key appears more than once, its last value is kept,
+            /// as if the entries had been inserted one by one.
             #[inline]
-            fn index_mut(&mut self, index: &Q) -> &mut Self::Output {
-                self.get_mut(index).expect("index not found")
+            pub fn from_box(bx: $boxed) -> Self
+            where
+                K: Ord,
+            {
+                Self::from_vec(bx.into_vec())
             }
-        }
 
-        impl<K: Ord, V> From<Vec<(K, V)>> for BinaryMap<K, V> {
+            /// Builds a map out of a list of entries, resolving duplicate keys with `policy`.
             #[inline]
-            fn from(mut inner: Vec<(K, V)>) -> Self {
-                inner.sort_unstable_by(|(x, _), (y, _)| x.cmp(y));
-                Self { inner }
+            pub fn from_box_with_policy(bx: $boxed, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<$boxed>>
+            where
+                K: Ord,
+            {
+                Self::from_vec_with_policy(bx.into_vec(), policy).map_err(|e| e.map(<$vec>::into_boxed_slice))
             }
-        }
 
-        impl<K: Ord, V> From<Box<[(K, V)]>> for BinaryMap<K, V> {
+            /// # Safety
+            /// The keys must be sorted and unique.
             #[inline]
-            fn from(inner: Box<[(K, V)]>) -> Self {
-                inner.into_vec().into()
+            pub unsafe fn from_box_unchecked(bx: $boxed) -> Self {
+                return Self::from_vec_unchecked(bx.into_vec());
             }
         }
 
-        impl<K: Ord, V, const N: usize> From<[(K, V); N]> for BinaryMap<K, V> {
+        impl<K: Ord, V, $($g)*> From<$vec> for BinaryMap<K, V, $vec> {
             #[inline]
-            fn from(inner: [(K, V); N]) -> Self {
-                Vec::from(inner).into()
+            fn from(inner: $vec) -> Self {
+                Self::from_vec(inner)
             }
         }
 
-        impl<K, V> Default for BinaryMap<K, V> {
+        impl<K: Ord, V, $($g)*> From<$boxed> for BinaryMap<K, V, $vec> {
             #[inline]
-            fn default() -> Self {
-                Self::new()
+            fn from(inner: $boxed) -> Self {
+                Self::from_box(inner)
             }
         }
+    };
+}
+
+#[cfg(feature = "allocator_api")]
+impl_vec_conversions!(impl[A: Allocator] Vec<(K, V), A>, Box<[(K, V)], A>);
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl_vec_conversions!(impl[] Vec<(K, V)>, Box<[(K, V)]>);
+
+#[cfg(feature = "allocator_api")]
+impl<K: Ord, V, A: Allocator + Default, const N: usize> From<[(K, V); N]> for BinaryMap<K, V, Vec<(K, V), A>> {
+    #[inline]
+    fn from(inner: [(K, V); N]) -> Self {
+        let mut vec = Vec::with_capacity_in(N, A::default());
+        vec.extend(inner);
+        Self::from_vec(vec)
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl<K: Ord, V, const N: usize> From<[(K, V); N]> for BinaryMap<K, V> {
+    #[inline]
+    fn from(inner: [(K, V); N]) -> Self {
+        Vec::from(inner).into()
     }
 }
+
+/// Builds a [`BinaryMap`] out of literal entries, in any order, allocating exactly once. Duplicate keys panic in
+/// debug builds, and keep their last value otherwise. An `in alloc;` prefix builds the map in the given allocator.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! binarymap {
+    ($(in $alloc:expr;)? $($key:expr => $value:expr),* $(,)?) => {
+        match $crate::binary::BinaryMap::from_vec_with_policy(
+            $crate::__literal_entries!($(in $alloc;)? $($key => $value),*),
+            $crate::__private::literal_policy(cfg!(debug_assertions)),
+        ) {
+            Ok(map) => map,
+            Err(err) => panic!("binarymap!: {}", err),
+        }
+    };
+}
@@ -0,0 +1,262 @@
+use super::BinaryMap;
+use crate::storage::MapStorage;
+#[cfg(feature = "alloc")]
+use crate::storage::HeapStorage;
+#[cfg(feature = "alloc")]
+use core::{
+    marker::PhantomData,
+    ops::{BitAnd, BitOr, BitXor, Sub},
+};
+use core::{
+    cmp::Ordering,
+    iter::{FusedIterator, Peekable},
+    slice,
+};
+
+type Side<'a, K, V> = Peekable<slice::Iter<'a, (K, V)>>;
+
+/// Compares the next keys of both sides, treating an exhausted side as greater than any key.
+#[inline]
+fn cmp_next<K: Ord, V>(left: &mut Side<'_, K, V>, right: &mut Side<'_, K, V>) -> Option<Ordering> {
+    return match (left.peek(), right.peek()) {
+        (Some((l, _)), Some((r, _))) => Some(l.cmp(r)),
+        (Some(_), None) => Some(Ordering::Less),
+        (None, Some(_)) => Some(Ordering::Greater),
+        (None, None) => None,
+    };
+}
+
+#[inline]
+fn entry<K, V>((k, v): &(K, V)) -> (&K, &V) {
+    (k, v)
+}
+
+/// A lazy iterator over the entries of either map, created by [`BinaryMap::union`].
+#[derive(Debug, Clone)]
+pub struct Union<'a, K, V> {
+    left: Side<'a, K, V>,
+    right: Side<'a, K, V>,
+}
+
+impl<'a, K: Ord, V> Iterator for Union<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match cmp_next(&mut self.left, &mut self.right)? {
+            Ordering::Less => self.left.next(),
+            Ordering::Greater => self.right.next(),
+            Ordering::Equal => {
+                self.right.next();
+                self.left.next()
+            }
+        };
+        next.map(entry)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left, right) = (self.left.len(), self.right.len());
+        (usize::max(left, right), left.checked_add(right))
+    }
+}
+
+/// A lazy iterator over the entries of the first map whose key is also in the second one,
+/// created by [`BinaryMap::intersection`].
+#[derive(Debug, Clone)]
+pub struct Intersection<'a, K, V> {
+    left: Side<'a, K, V>,
+    right: Side<'a, K, V>,
+}
+
+impl<'a, K: Ord, V> Iterator for Intersection<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.left.peek().is_none() || self.right.peek().is_none() {
+                return None;
+            }
+
+            match cmp_next(&mut self.left, &mut self.right)? {
+                Ordering::Less => {
+                    self.left.next();
+                }
+                Ordering::Greater => {
+                    self.right.next();
+                }
+                Ordering::Equal => {
+                    self.right.next();
+                    return self.left.next().map(entry);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(usize::min(self.left.len(), self.right.len())))
+    }
+}
+
+/// A lazy iterator over the entries of the first map whose key isn't in the second one,
+/// created by [`BinaryMap::difference`].
+#[derive(Debug, Clone)]
+pub struct Difference<'a, K, V> {
+    left: Side<'a, K, V>,
+    right: Side<'a, K, V>,
+}
+
+impl<'a, K: Ord, V> Iterator for Difference<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match cmp_next(&mut self.left, &mut self.right)? {
+                Ordering::Less => return self.left.next().map(entry),
+                Ordering::Greater if self.left.peek().is_none() => return None,
+                Ordering::Greater => {
+                    self.right.next();
+                }
+                Ordering::Equal => {
+                    self.left.next();
+                    self.right.next();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left, right) = (self.left.len(), self.right.len());
+        (left.saturating_sub(right), Some(left))
+    }
+}
+
+/// A lazy iterator over the entries whose key is in exactly one of the maps,
+/// created by [`BinaryMap::symmetric_difference`].
+#[derive(Debug, Clone)]
+pub struct SymmetricDifference<'a, K, V> {
+    left: Side<'a, K, V>,
+    right: Side<'a, K, V>,
+}
+
+impl<'a, K: Ord, V> Iterator for SymmetricDifference<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match cmp_next(&mut self.left, &mut self.right)? {
+                Ordering::Less => return self.left.next().map(entry),
+                Ordering::Greater => return self.right.next().map(entry),
+                Ordering::Equal => {
+                    self.left.next();
+                    self.right.next();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.left.len().checked_add(self.right.len()))
+    }
+}
+
+impl<K: Ord, V> FusedIterator for Union<'_, K, V> {}
+impl<K: Ord, V> FusedIterator for Intersection<'_, K, V> {}
+impl<K: Ord, V> FusedIterator for Difference<'_, K, V> {}
+impl<K: Ord, V> FusedIterator for SymmetricDifference<'_, K, V> {}
+
+impl<K: Ord, V, S: MapStorage<K, V>> BinaryMap<K, V, S> {
+    /// Iterates the entries whose key is in either map, in ascending order, in O(n + m).
+    /// Keys present in both maps yield the entry of `self`.
+    #[inline]
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, K, V> {
+        Union {
+            left: self.inner.as_slice().iter().peekable(),
+            right: other.inner.as_slice().iter().peekable(),
+        }
+    }
+
+    /// Iterates the entries of `self` whose key is also in `other`, in ascending order, in O(n + m).
+    #[inline]
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, K, V> {
+        Intersection {
+            left: self.inner.as_slice().iter().peekable(),
+            right: other.inner.as_slice().iter().peekable(),
+        }
+    }
+
+    /// Iterates the entries of `self` whose key isn't in `other`, in ascending order, in O(n + m).
+    #[inline]
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, K, V> {
+        Difference {
+            left: self.inner.as_slice().iter().peekable(),
+            right: other.inner.as_slice().iter().peekable(),
+        }
+    }
+
+    /// Iterates the entries whose key is in exactly one of the maps, in ascending order, in O(n + m).
+    #[inline]
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, K, V> {
+        SymmetricDifference {
+            left: self.inner.as_slice().iter().peekable(),
+            right: other.inner.as_slice().iter().peekable(),
+        }
+    }
+
+    /// Returns `true` if every key of `self` is also in `other`. Values are not compared.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.difference(other).next().is_none()
+    }
+
+    /// Returns `true` if every key of `other` is also in `self`. Values are not compared.
+    #[inline]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if the maps have no keys in common.
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
+    }
+}
+
+macro_rules! impl_ops {
+    ($($trait:ident :: $fn:ident => $iter:ident),+ $(,)?) => {
+        $(
+            #[cfg(feature = "alloc")]
+            impl<K: Ord + Clone, V: Clone, S: HeapStorage<K, V>> $trait<&BinaryMap<K, V, S>> for &BinaryMap<K, V, S>
+            where
+                S::Alloc: Clone,
+            {
+                type Output = BinaryMap<K, V, S>;
+
+                #[inline]
+                fn $fn(self, rhs: &BinaryMap<K, V, S>) -> Self::Output {
+                    let mut inner = S::with_capacity_in(0, self.inner.allocator().clone());
+                    for (k, v) in self.$iter(rhs) {
+                        inner.insert_growing(inner.len(), (k.clone(), v.clone()));
+                    }
+                    BinaryMap {
+                        inner,
+                        _marker: PhantomData,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_ops! {
+    BitOr::bitor => union,
+    BitAnd::bitand => intersection,
+    Sub::sub => difference,
+    BitXor::bitxor => symmetric_difference,
+}
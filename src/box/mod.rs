@@ -1,21 +1,21 @@
 macro_rules! impl_all {
     ($(where $($trait:path),+ =>)? { $($t:tt)* }) => {
-        #[cfg(feature = "alloc")]
+        #[cfg(feature = "allocator_api")]
         impl<K, V, A: Allocator> BoxMap<K, V, A> $(where K: $($trait+)*)? {
             $($t)*
         }
 
-        #[cfg(not(feature = "alloc"))]
+        #[cfg(not(feature = "allocator_api"))]
         impl<K, V> BoxMap<K, V> $(where K: $($trait+)*)? {
             $($t)*
         }
     };
 }
 
-use crate::vec::VecMap;
-#[cfg(feature = "alloc")]
-use alloc::alloc::*;
-use alloc::{boxed::*, vec::Vec};
+use crate::{search::SearchKey, vec::{many, DuplicateKeysError, DuplicatePolicy, OrderPolicy, VecMap}};
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use crate::heap::{boxed::*, vec::Vec};
 use core::fmt::Debug;
 use core::{
     borrow::Borrow,
@@ -23,21 +23,21 @@ use core::{
 };
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        pub type IntoIter<K, V, A = Global> = alloc::vec::IntoIter<(K, V), A>;
-        pub type Drain<'a, K, V, A = Global> = alloc::vec::Drain<'a, (K, V), A>;
+    if #[cfg(feature = "allocator_api")] {
+        pub type IntoIter<K, V, A = Global> = crate::heap::vec::IntoIter<(K, V), A>;
+        pub type Drain<'a, K, V, A = Global> = crate::heap::vec::Drain<'a, (K, V), A>;
     } else {
-        pub type IntoIter<K, V> = alloc::vec::IntoIter<(K, V)>;
-        pub type Drain<'a, K, V> = alloc::vec::Drain<'a, (K, V)>;
+        pub type IntoIter<K, V> = crate::heap::vec::IntoIter<(K, V)>;
+        pub type Drain<'a, K, V> = crate::heap::vec::Drain<'a, (K, V)>;
     }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
-pub struct BoxMap<K, V, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
+pub struct BoxMap<K, V, #[cfg(feature = "allocator_api")] A: Allocator = Global> {
+    #[cfg(feature = "allocator_api")]
     inner: Box<[(K, V)], A>,
-    #[cfg(not(feature = "alloc"))]
+    #[cfg(not(feature = "allocator_api"))]
     inner: Box<[(K, V)]>,
 }
 
@@ -83,6 +83,55 @@ impl_all! {
         pub fn contains_key<Q: ?Sized + Eq> (&self, k: &Q) -> bool where K: Borrow<Q> {
             self.keys().any(|x| x.borrow() == k)
         }
+
+        /// Returns references to the values of all the keys, or `None` if any of them is missing.
+        #[inline]
+        pub fn get_many<Q: ?Sized + Eq, const N: usize> (&self, keys: [&Q; N]) -> Option<[&V; N]> where K: Borrow<Q> {
+            let indices = many::indices(keys, |key| Q::position(&self.inner, key, |(k, _)| k))?;
+            return Some(many::values(&self.inner, indices))
+        }
+
+        /// Returns mutable references to the values of all the keys, or `None` if any of them is missing
+        /// or if two of them refer to the same entry.
+        #[inline]
+        pub fn get_many_mut<Q: ?Sized + Eq, const N: usize> (&mut self, keys: [&Q; N]) -> Option<[&mut V; N]> where K: Borrow<Q> {
+            let indices = many::indices(keys, |key| Q::position(&self.inner, key, |(k, _)| k))?;
+            if !many::is_disjoint(&indices) { return None }
+            return unsafe { Some(many::values_mut(&mut self.inner, indices)) }
+        }
+
+        /// Returns mutable references to the values of all the keys, or `None` if any of them is missing.
+        ///
+        /// # Safety
+        /// No two keys may refer to the same entry.
+        #[inline]
+        pub unsafe fn get_many_unchecked_mut<Q: ?Sized + Eq, const N: usize> (&mut self, keys: [&Q; N]) -> Option<[&mut V; N]> where K: Borrow<Q> {
+            let indices = many::indices(keys, |key| Q::position(&self.inner, key, |(k, _)| k))?;
+            debug_assert!(many::is_disjoint(&indices));
+            return Some(many::values_mut(&mut self.inner, indices))
+        }
+
+        /// Returns the index of the first entry whose key matches the predicate.
+        #[inline]
+        pub fn position_by<F: FnMut(&K) -> bool> (&self, mut f: F) -> Option<usize> {
+            return self.inner.iter().position(|(k, _)| f(k))
+        }
+
+        /// Returns the first entry whose key matches the predicate, along with its index.
+        #[inline]
+        pub fn find_by<F: FnMut(&K) -> bool> (&self, f: F) -> Option<(usize, &K, &V)> {
+            let idx = self.position_by(f)?;
+            let (k, v) = unsafe { self.inner.get_unchecked(idx) };
+            return Some((idx, k, v))
+        }
+
+        /// Returns the first entry whose key matches the predicate, along with its index.
+        #[inline]
+        pub fn find_by_mut<F: FnMut(&K) -> bool> (&mut self, f: F) -> Option<(usize, &K, &mut V)> {
+            let idx = self.position_by(f)?;
+            let (k, v) = unsafe { self.inner.get_unchecked_mut(idx) };
+            return Some((idx, k, v))
+        }
     }
 }
 
@@ -113,18 +162,48 @@ impl_all! {{
     }
 }}
 
-#[cfg(feature = "alloc")]
+#[cfg(feature = "allocator_api")]
 impl<K, V, A: Allocator> BoxMap<K, V, A> {
+    /// # Safety
+    /// The keys must be unique.
     #[inline]
     pub unsafe fn from_vec_unchecked (vec: Vec<(K, V), A>) -> Self {
         return Self::from_box_unchecked(vec.into_boxed_slice())
     }
 
+    /// # Safety
+    /// The keys must be unique.
     #[inline]
     pub unsafe fn from_box_unchecked (bx: Box<[(K, V)], A>) -> Self {
         return Self { inner: bx }
     }
 
+    /// Builds a map out of a list of entries, resolving duplicate keys with `policy`. Runs in O(n²), even if `K: Hash`,
+    /// since there is no hash set without `std`. Use [`from_vec_ord`](Self::from_vec_ord) for keys that are `Ord`.
+    #[inline]
+    pub fn from_vec (vec: Vec<(K, V), A>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Vec<(K, V), A>>> where K: Eq {
+        return VecMap::<K, V, Vec<(K, V), A>>::from_vec(vec, policy).map(Into::into)
+    }
+
+    /// Same as [`from_vec`](Self::from_vec), but runs in O(n log n) by sorting the keys.
+    #[inline]
+    pub fn from_vec_ord (vec: Vec<(K, V), A>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Vec<(K, V), A>>> where K: Ord {
+        return VecMap::<K, V, Vec<(K, V), A>>::from_vec_ord(vec, policy).map(Into::into)
+    }
+
+    /// Builds a map out of a list of entries, resolving duplicate keys with `policy`. Runs in O(n²), even if `K: Hash`,
+    /// since there is no hash set without `std`. Use [`from_box_ord`](Self::from_box_ord) for keys that are `Ord`.
+    #[inline]
+    pub fn from_box (bx: Box<[(K, V)], A>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Box<[(K, V)], A>>> where K: Eq {
+        return Self::from_vec(bx.into_vec(), policy).map_err(|e| e.map(Vec::into_boxed_slice))
+    }
+
+    /// Same as [`from_box`](Self::from_box), but runs in O(n log n) by sorting the keys.
+    #[inline]
+    pub fn from_box_ord (bx: Box<[(K, V)], A>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Box<[(K, V)], A>>> where K: Ord {
+        return Self::from_vec_ord(bx.into_vec(), policy).map_err(|e| e.map(Vec::into_boxed_slice))
+    }
+
     #[inline]
     pub fn into_vec(self) -> Vec<(K, V), A> {
         return self.inner.into_vec();
@@ -144,10 +223,34 @@ impl<K, V, A: Allocator> BoxMap<K, V, A> {
     pub fn into_values(self) -> IntoValues<K, V, A> {
         return IntoValues(self.into_vec().into_iter());
     }
+
+    /// Returns a new (possibly shorter) map with only the entries that match the predicate.
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(self, mut f: F) -> Self {
+        let mut vec = self.into_vec();
+        vec.retain_mut(|(k, v)| f(k, v));
+        return unsafe { Self::from_vec_unchecked(vec) };
+    }
+
+    /// Removes the entries that match the predicate, returning the new map alongside them.
+    /// Unlike [`VecMap::extract_if`], the extraction is performed eagerly.
+    #[inline]
+    pub fn extract_if<F: FnMut(&K, &mut V) -> bool>(self, pred: F) -> (Self, IntoIter<K, V, A>) where A: Clone {
+        let (extracted, rest) = self.partition(pred);
+        return (rest, extracted.into_iter());
+    }
+
+    /// Splits the map in two, with the entries that match the predicate on the first one,
+    /// and the ones that don't on the second.
+    #[inline]
+    pub fn partition<F: FnMut(&K, &mut V) -> bool>(self, pred: F) -> (Self, Self) where A: Clone {
+        let (lhs, rhs) = VecMap::<K, V, Vec<(K, V), A>>::from(self).partition(pred);
+        return (lhs.into(), rhs.into());
+    }
 }
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
+    if #[cfg(feature = "allocator_api")] {
         impl<K, V, A: Allocator> IntoIterator for BoxMap<K, V, A> {
             type Item = (K, V);
             type IntoIter = IntoIter<K, V, A>;
@@ -181,14 +284,14 @@ cfg_if::cfg_if! {
         impl<K: Eq, V, A: Allocator + Default> FromIterator<(K, V)> for BoxMap<K, V, A> {
             #[inline]
             fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
-                VecMap::from_iter(iter).into()
+                VecMap::<K, V, Vec<(K, V), A>>::from_iter(iter).into()
             }
         }
 
         impl<K, V, A: Allocator + Default> Default for BoxMap<K, V, A> {
             #[inline]
             fn default () -> Self {
-                return Self { inner: Box::new_in([], A::default()) }
+                return Self { inner: Vec::new_in(A::default()).into_boxed_slice() }
             }
         }
 
@@ -199,9 +302,22 @@ cfg_if::cfg_if! {
             }
         }
 
-        impl<K, V, A: Allocator> From<VecMap<K, V, A>> for BoxMap<K, V, A> {
+        /// Fails if any key appears more than once, listing the indices of the duplicates.
+        ///
+        /// Since it only requires `K: Eq`, the keys are compared pairwise in O(n²). For keys that are `Ord`,
+        /// [`BoxMap::from_vec_ord`] with [`DuplicatePolicy::Error`] does the same in O(n log n).
+        impl<K: Eq, V, A: Allocator> TryFrom<Vec<(K, V), A>> for BoxMap<K, V, A> {
+            type Error = DuplicateKeysError<Vec<(K, V), A>>;
+
+            #[inline]
+            fn try_from(value: Vec<(K, V), A>) -> Result<Self, Self::Error> {
+                Self::from_vec(value, DuplicatePolicy::Error)
+            }
+        }
+
+        impl<K, V, A: Allocator, P: OrderPolicy> From<VecMap<K, V, Vec<(K, V), A>, P>> for BoxMap<K, V, A> {
             #[inline]
-            fn from(value: VecMap<K, V, A>) -> Self {
+            fn from(value: VecMap<K, V, Vec<(K, V), A>, P>) -> Self {
                 Self { inner: value.into_box() }
             }
         }
@@ -223,16 +339,46 @@ cfg_if::cfg_if! {
         }
     } else {
         impl<K, V> BoxMap<K, V> {
+            /// # Safety
+            /// The keys must be unique.
             #[inline]
             pub unsafe fn from_vec_unchecked (vec: Vec<(K, V)>) -> Self {
                 return Self::from_box_unchecked(vec.into_boxed_slice())
             }
 
+            /// # Safety
+            /// The keys must be unique.
             #[inline]
             pub unsafe fn from_box_unchecked (bx: Box<[(K, V)]>) -> Self {
                 return Self { inner: bx }
             }
 
+            /// Builds a map out of a list of entries, resolving duplicate keys with `policy`. Runs in O(n²), even if `K: Hash`,
+            /// since there is no hash set without `std`. Use [`from_vec_ord`](Self::from_vec_ord) for keys that are `Ord`.
+            #[inline]
+            pub fn from_vec (vec: Vec<(K, V)>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Vec<(K, V)>>> where K: Eq {
+                return VecMap::<K, V>::from_vec(vec, policy).map(Into::into)
+            }
+
+            /// Same as [`from_vec`](Self::from_vec), but runs in O(n log n) by sorting the keys.
+            #[inline]
+            pub fn from_vec_ord (vec: Vec<(K, V)>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Vec<(K, V)>>> where K: Ord {
+                return VecMap::<K, V>::from_vec_ord(vec, policy).map(Into::into)
+            }
+
+            /// Builds a map out of a list of entries, resolving duplicate keys with `policy`. Runs in O(n²), even if `K: Hash`,
+            /// since there is no hash set without `std`. Use [`from_box_ord`](Self::from_box_ord) for keys that are `Ord`.
+            #[inline]
+            pub fn from_box (bx: Box<[(K, V)]>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Box<[(K, V)]>>> where K: Eq {
+                return Self::from_vec(bx.into_vec(), policy).map_err(|e| e.map(Vec::into_boxed_slice))
+            }
+
+            /// Same as [`from_box`](Self::from_box), but runs in O(n log n) by sorting the keys.
+            #[inline]
+            pub fn from_box_ord (bx: Box<[(K, V)]>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<Box<[(K, V)]>>> where K: Ord {
+                return Self::from_vec_ord(bx.into_vec(), policy).map_err(|e| e.map(Vec::into_boxed_slice))
+            }
+
             #[inline]
             pub fn into_vec (self) -> Vec<(K, V)> {
                 return self.inner.into_vec()
@@ -252,6 +398,30 @@ cfg_if::cfg_if! {
             pub fn into_values (self) -> IntoValues<K, V> {
                 return IntoValues(self.into_vec().into_iter())
             }
+
+            /// Returns a new (possibly shorter) map with only the entries that match the predicate.
+            #[inline]
+            pub fn retain<F: FnMut(&K, &mut V) -> bool> (self, mut f: F) -> Self {
+                let mut vec = self.into_vec();
+                vec.retain_mut(|(k, v)| f(k, v));
+                return unsafe { Self::from_vec_unchecked(vec) }
+            }
+
+            /// Removes the entries that match the predicate, returning the new map alongside them.
+            /// Unlike [`VecMap::extract_if`], the extraction is performed eagerly.
+            #[inline]
+            pub fn extract_if<F: FnMut(&K, &mut V) -> bool> (self, pred: F) -> (Self, IntoIter<K, V>) {
+                let (extracted, rest) = self.partition(pred);
+                return (rest, extracted.into_iter())
+            }
+
+            /// Splits the map in two, with the entries that match the predicate on the first one,
+            /// and the ones that don't on the second.
+            #[inline]
+            pub fn partition<F: FnMut(&K, &mut V) -> bool> (self, pred: F) -> (Self, Self) {
+                let (lhs, rhs) = VecMap::<K, V>::from(self).partition(pred);
+                return (lhs.into(), rhs.into())
+            }
         }
 
         impl<K, V> IntoIterator for BoxMap<K, V> {
@@ -287,7 +457,7 @@ cfg_if::cfg_if! {
         impl<K: Eq, V> FromIterator<(K, V)> for BoxMap<K, V> {
             #[inline]
             fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
-                VecMap::from_iter(iter).into()
+                VecMap::<K, V>::from_iter(iter).into()
             }
         }
 
@@ -305,9 +475,22 @@ cfg_if::cfg_if! {
             }
         }
 
-        impl<K, V> From<VecMap<K, V>> for BoxMap<K, V> {
+        /// Fails if any key appears more than once, listing the indices of the duplicates.
+        ///
+        /// Since it only requires `K: Eq`, the keys are compared pairwise in O(n²). For keys that are `Ord`,
+        /// [`BoxMap::from_vec_ord`] with [`DuplicatePolicy::Error`] does the same in O(n log n).
+        impl<K: Eq, V> TryFrom<Vec<(K, V)>> for BoxMap<K, V> {
+            type Error = DuplicateKeysError<Vec<(K, V)>>;
+
+            #[inline]
+            fn try_from(value: Vec<(K, V)>) -> Result<Self, Self::Error> {
+                Self::from_vec(value, DuplicatePolicy::Error)
+            }
+        }
+
+        impl<K, V, P: OrderPolicy> From<VecMap<K, V, Vec<(K, V)>, P>> for BoxMap<K, V> {
             #[inline]
-            fn from(value: VecMap<K, V>) -> Self {
+            fn from(value: VecMap<K, V, Vec<(K, V)>, P>) -> Self {
                 Self { inner: value.into_box() }
             }
         }
@@ -332,7 +515,7 @@ cfg_if::cfg_if! {
 
 #[derive(Debug, Clone)]
 #[repr(transparent)]
-pub struct Keys<'a, K: 'a, V: 'a>(core::slice::Iter<'a, (K, V)>);
+pub struct Keys<'a, K: 'a, V: 'a>(pub(super) core::slice::Iter<'a, (K, V)>);
 
 impl<'a, K, V> Iterator for Keys<'a, K, V> {
     type Item = &'a K;
@@ -521,9 +704,9 @@ impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {
 
 #[derive(Debug, Clone)]
 #[repr(transparent)]
-pub struct IntoKeys<K, V, #[cfg(feature = "alloc")] A: Allocator = Global>(
-    #[cfg(feature = "alloc")] alloc::vec::IntoIter<(K, V), A>,
-    #[cfg(not(feature = "alloc"))] alloc::vec::IntoIter<(K, V)>,
+pub struct IntoKeys<K, V, #[cfg(feature = "allocator_api")] A: Allocator = Global>(
+    #[cfg(feature = "allocator_api")] crate::heap::vec::IntoIter<(K, V), A>,
+    #[cfg(not(feature = "allocator_api"))] crate::heap::vec::IntoIter<(K, V)>,
 );
 
 impl<K, V> Iterator for IntoKeys<K, V> {
@@ -587,9 +770,9 @@ impl<K, V> ExactSizeIterator for IntoKeys<K, V> {
 
 #[derive(Debug, Clone)]
 #[repr(transparent)]
-pub struct IntoValues<K, V, #[cfg(feature = "alloc")] A: Allocator = Global>(
-    #[cfg(feature = "alloc")] alloc::vec::IntoIter<(K, V), A>,
-    #[cfg(not(feature = "alloc"))] alloc::vec::IntoIter<(K, V)>,
+pub struct IntoValues<K, V, #[cfg(feature = "allocator_api")] A: Allocator = Global>(
+    #[cfg(feature = "allocator_api")] crate::heap::vec::IntoIter<(K, V), A>,
+    #[cfg(not(feature = "allocator_api"))] crate::heap::vec::IntoIter<(K, V)>,
 );
 
 impl<K, V> Iterator for IntoValues<K, V> {
@@ -776,3 +959,18 @@ impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {
         self.0.len()
     }
 }
+
+/// Builds a [`BoxMap`] out of literal entries, allocating exactly once. Duplicate keys panic in debug builds,
+/// and keep their last value otherwise. An `in alloc;` prefix builds the map in the given allocator.
+#[macro_export]
+macro_rules! boxmap {
+    ($(in $alloc:expr;)? $($key:expr => $value:expr),* $(,)?) => {
+        match $crate::r#box::BoxMap::from_vec(
+            $crate::__literal_entries!($(in $alloc;)? $($key => $value),*),
+            $crate::__private::literal_policy(cfg!(debug_assertions)),
+        ) {
+            Ok(map) => map,
+            Err(err) => panic!("boxmap!: {}", err),
+        }
+    };
+}
@@ -1,6 +1,29 @@
+//! Maps backed by a list of entries, searched linearly or by binary search.
+//!
+//! # Features
+//! - `alloc` (default): links the `alloc` crate, enabling the heap-backed maps. Without it, [`VecMap`](vec::VecMap)
+//!   and [`BinaryMap`](binary::BinaryMap) can still be backed by any [`MapStorage`](storage::MapStorage), alongside
+//!   the slice and array maps.
+//! - `allocator_api`: adds a custom allocator parameter to the heap-backed maps. Requires nightly, unless
+//!   `allocator-api2` is enabled too. [`BoxMap`](crate::box::BoxMap) and [`BoxSet`](set::BoxSet) take it directly,
+//!   while the maps and sets that are generic over their storage (including the small, self-organizing and
+//!   struct-of-arrays maps) take it through a `Vec<_, A>`, and get `new_in`/`with_capacity_in` constructors.
+//! - `allocator-api2`: takes the allocator parameter from the `allocator-api2` crate, so that it works on stable.
+//! - `heapless`: lets a `heapless::Vec` back a [`VecMap`](vec::VecMap) or [`BinaryMap`](binary::BinaryMap).
+//! - `serde`.
+//! - `simd`: compares `u8`, `u16`, `u32`, `u64` and `char` keys a whole SSE2 vector at a time in the linear
+//!   searches, on x86 targets. Works on stable, and falls back to a plain scan on other targets.
+//!
+//! # Migrating from 0.3
+//! Up to 0.3, the `alloc` feature added the custom allocator parameter, and the heap-backed maps were always
+//! available. That feature is now named `allocator_api`, while `alloc` only links the `alloc` crate and is enabled
+//! by default. Replace `features = ["alloc"]` with `features = ["allocator_api"]`, and add `"alloc"` to the
+//! features of crates that set `default-features = false`.
+
 #![no_std]
-#![cfg_attr(feature = "alloc", feature(allocator_api))]
+#![cfg_attr(all(feature = "allocator_api", not(feature = "allocator-api2")), feature(allocator_api))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![allow(clippy::needless_return, clippy::type_complexity)]
 
 macro_rules! flat_mod {
     ($($i:ident),+) => {
@@ -11,11 +34,123 @@ macro_rules! flat_mod {
     }
 }
 
+/// Implements the iterator traits for a newtype by forwarding them to its inner iterator.
+macro_rules! forward_iter {
+    ($(impl[$($g:tt)*] $name:ty => $item:ty;)+) => {
+        $(
+            impl<$($g)*> Iterator for $name {
+                type Item = $item;
+
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.0.next()
+                }
+
+                #[inline]
+                fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                    self.0.nth(n)
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.0.size_hint()
+                }
+            }
+
+            impl<$($g)*> DoubleEndedIterator for $name {
+                #[inline]
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    self.0.next_back()
+                }
+
+                #[inline]
+                fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                    self.0.nth_back(n)
+                }
+            }
+
+            impl<$($g)*> ExactSizeIterator for $name {
+                #[inline]
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+            }
+
+            impl<$($g)*> core::iter::FusedIterator for $name {}
+        )+
+    };
+}
+
+#[cfg(feature = "alloc")]
 pub(crate) extern crate alloc;
 
+/// The heap types backing the maps, taken from `allocator-api2` instead of `alloc` when that feature is enabled.
+#[cfg(feature = "alloc")]
+pub(crate) mod heap {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "allocator-api2")] {
+            pub(crate) use allocator_api2::{alloc, boxed, collections, vec};
+        } else {
+            #[cfg(feature = "allocator_api")]
+            pub(crate) use ::alloc::alloc;
+            pub(crate) use ::alloc::{boxed, collections, vec};
+        }
+    }
+}
+
+/// Implementation details of the exported macros.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::heap::vec::Vec;
+    pub use crate::vec::DuplicatePolicy;
+
+    /// The policy used by the literal macros: duplicate keys are rejected in debug builds, and the last value
+    /// wins otherwise. Takes the caller's `cfg!(debug_assertions)`, not this crate's.
+    #[inline]
+    pub const fn literal_policy (debug: bool) -> DuplicatePolicy {
+        return if debug { DuplicatePolicy::Error } else { DuplicatePolicy::KeepLast }
+    }
+}
+
+/// Collects the entries of a literal map macro into a vector of the exact capacity, allocated in the
+/// allocator of the `in` clause if there is one.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __literal_entries {
+    (@unit $_:expr) => { () };
+    (in $alloc:expr; $($key:expr => $value:expr),*) => {{
+        let mut entries = $crate::__private::Vec::with_capacity_in(<[()]>::len(&[$($crate::__literal_entries!(@unit $key)),*]), $alloc);
+        $(entries.push(($key, $value));)*
+        entries
+    }};
+    ($($key:expr => $value:expr),*) => {{
+        let mut entries = $crate::__private::Vec::with_capacity(<[()]>::len(&[$($crate::__literal_entries!(@unit $key)),*]));
+        $(entries.push(($key, $value));)*
+        entries
+    }};
+}
+
 #[cfg(feature = "serde")]
-flat_mod! { serde_impl }
+mod serde_impl;
+
+pub(crate) mod search;
+pub mod slice;
+pub mod array;
+pub mod storage;
 
 pub mod vec;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub mod r#box;
-pub mod binary;
\ No newline at end of file
+pub mod binary;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod soa;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod set;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod small;
@@ -0,0 +1,24 @@
+use core::borrow::Borrow;
+
+/// Linear key search over the entries of a map, where `f` projects each entry into its key, which must be
+/// stored inside of it.
+///
+/// Every `Eq` type uses a plain scan. With the `simd` feature enabled, `u8`, `u16`, `u32`, `u64` and `char`
+/// keys are compared a whole vector at a time on targets with SSE2, and fall back to the scan elsewhere.
+pub(crate) trait SearchKey: Eq {
+    fn position<T, K: Borrow<Self>, F: Fn(&T) -> &K>(entries: &[T], key: &Self, f: F) -> Option<usize>;
+}
+
+impl<Q: ?Sized + Eq> SearchKey for Q {
+    #[inline]
+    fn position<T, K: Borrow<Self>, F: Fn(&T) -> &K>(entries: &[T], key: &Self, f: F) -> Option<usize> {
+        #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+        if let Some(idx) = simd::position(entries, key, &f) {
+            return idx
+        }
+        return entries.iter().position(|x| f(x).borrow() == key)
+    }
+}
+
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+mod simd;
@@ -0,0 +1,131 @@
+use core::{any::TypeId, borrow::Borrow, marker::PhantomData, mem::size_of};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Searches for `key` a whole SSE2 vector of keys at a time if `Q` is a primitive key, or returns `None` so
+/// that the caller falls back to a scalar scan.
+#[inline]
+pub(super) fn position<T, Q: ?Sized, K: Borrow<Q>, F: Fn(&T) -> &K> (entries: &[T], key: &Q, f: &F) -> Option<Option<usize>> {
+    macro_rules! dispatch {
+        ($($t:ty),+) => {
+            $(
+                if is::<Q, $t>() {
+                    // `Q` is `$t`, so the references can be cast between them
+                    let key = unsafe { *(key as *const Q as *const $t) };
+                    return Some(search::<T, $t, _>(entries, key, |x| unsafe { &*(f(x).borrow() as *const Q as *const $t) }))
+                }
+            )+
+        };
+    }
+
+        dispatch!(u8, u16, u32, u64, char);
+    return None
+}
+
+/// Whether `Q` is `T`. [`TypeId`] is only available for `'static` types, so it's taken through a trait object
+/// whose lifetime is erased. This is sound because `T` has no lifetimes that could be confused with those of `Q`.
+#[inline]
+fn is<Q: ?Sized, T: 'static> () -> bool {
+    trait NonStaticAny {
+        fn type_id (&self) -> TypeId where Self: 'static;
+    }
+
+    impl<Q: ?Sized> NonStaticAny for PhantomData<Q> {
+        #[inline]
+        fn type_id (&self) -> TypeId where Self: 'static {
+            return TypeId::of::<Q>()
+        }
+    }
+
+    let phantom = PhantomData::<Q>;
+    let phantom = unsafe { core::mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom) };
+    return phantom.type_id() == TypeId::of::<T>()
+}
+
+/// Compares the keys a chunk of [`LANES`](Lane::LANES) entries at a time.
+///
+/// Keys that fill their whole entry (the keys of the struct-of-arrays maps, or those of the sets, whose values
+/// are `()`) are laid out back to back, so each chunk is loaded straight into a vector. Otherwise, the keys of
+/// the chunk are copied into a buffer first.
+#[inline]
+fn search<T, L: Lane, G: Fn(&T) -> &L> (entries: &[T], key: L, get: G) -> Option<usize> {
+    let first = entries.first()?;
+    let contiguous = size_of::<T>() == size_of::<L>() && core::ptr::eq(get(first) as *const L as *const u8, first as *const T as *const u8);
+
+    let mut chunks = entries.chunks_exact(L::LANES);
+    let mut offset = 0;
+
+    for chunk in chunks.by_ref() {
+        let mask = match contiguous {
+            true => unsafe { L::matches(chunk.as_ptr().cast(), key) },
+            false => {
+                let mut keys = [key; 16];
+                for (slot, x) in keys.iter_mut().zip(chunk) {
+                    *slot = *get(x);
+                }
+                unsafe { L::matches(keys.as_ptr(), key) }
+            }
+        };
+
+        if mask != 0 {
+            return Some(offset + mask.trailing_zeros() as usize / size_of::<L>())
+        }
+        offset += L::LANES;
+    }
+
+    return chunks.remainder()
+        .iter()
+        .position(|x| *get(x) == key)
+        .map(|i| offset + i)
+}
+
+/// A primitive key that's compared a whole 128-bit vector at a time.
+trait Lane: 'static + Copy + Eq {
+    /// How many keys fit in a vector.
+    const LANES: usize = 16 / size_of::<Self>();
+
+    /// Returns a mask with a bit set for every byte of the keys at `chunk` that equal `key`.
+    ///
+    /// # Safety
+    /// `chunk` must be valid for reads of [`LANES`](Lane::LANES) keys.
+    unsafe fn matches (chunk: *const Self, key: Self) -> u32;
+}
+
+macro_rules! impl_lane {
+    ($($t:ty as $i:ty => $splat:ident, $cmp:ident),+) => {
+        $(
+            impl Lane for $t {
+                #[inline]
+                unsafe fn matches (chunk: *const Self, key: Self) -> u32 {
+                    let eq = $cmp(_mm_loadu_si128(chunk.cast()), $splat(key as $i));
+                    return _mm_movemask_epi8(eq) as u32
+                }
+            }
+        )+
+    };
+}
+
+impl_lane! {
+    u8 as i8 => _mm_set1_epi8, _mm_cmpeq_epi8,
+    u16 as i16 => _mm_set1_epi16, _mm_cmpeq_epi16,
+    u32 as i32 => _mm_set1_epi32, _mm_cmpeq_epi32
+}
+
+impl Lane for char {
+    #[inline]
+    unsafe fn matches (chunk: *const Self, key: Self) -> u32 {
+        return u32::matches(chunk.cast(), key as u32)
+    }
+}
+
+impl Lane for u64 {
+    #[inline]
+    unsafe fn matches (chunk: *const Self, key: Self) -> u32 {
+        // SSE2 can't compare 64-bit lanes, so both of their 32-bit halves have to match
+        let eq = _mm_cmpeq_epi32(_mm_loadu_si128(chunk.cast()), _mm_set1_epi64x(key as i64));
+        let eq = _mm_and_si128(eq, _mm_shuffle_epi32(eq, 0b10_11_00_01));
+        return _mm_movemask_epi8(eq) as u32
+    }
+}
@@ -1,23 +1,134 @@
-#[cfg(feature = "alloc")]
-use core::alloc::*;
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
 use core::marker::PhantomData;
+#[cfg(feature = "allocator_api")]
+use crate::heap::vec::Vec;
+#[cfg(feature = "alloc")]
+use serde::ser::SerializeSeq;
 use serde::{Serialize, ser::SerializeMap, Deserialize, de::Visitor};
-use crate::{vec::VecMap, r#box::BoxMap, binary::BinaryMap};
+use crate::{array::{ArrayMap, ArrayBinaryMap}, slice::{SliceMap, SliceMapMut, StaticBinaryMap}, storage::MapStorage, vec::{OrderPolicy, VecMap}, binary::BinaryMap};
+#[cfg(feature = "alloc")]
+use crate::{r#box::BoxMap, soa::{SoaBuffer, SoaVecMap, SoaBinaryMap, SoaBoxMap}, set::{VecSet, BinarySet, BoxSet}, small::{SmallVecMap, SmallBinaryMap}, storage::HeapStorage};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, St: MapStorage<K, V>, P: OrderPolicy> Serialize for VecMap<K, V, St, P> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut ser = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self {
+            ser.serialize_entry(key, value)?;
+        }
+        return ser.end()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, St: MapStorage<K, V>> Serialize for BinaryMap<K, V, St> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut ser = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self {
+            ser.serialize_entry(key, value)?;
+        }
+        return ser.end()
+    }
+}
+
+/// Caps the size hint of a deserializer, so a bogus one doesn't preallocate more than a megabyte.
+#[inline]
+fn cautious_hint<K, V> (hint: Option<usize>) -> usize {
+    const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+    return match core::mem::size_of::<(K, V)>() {
+        0 => 0,
+        size => usize::min(hint.unwrap_or(0), MAX_PREALLOC_BYTES / size)
+    }
+}
+
+/// The error of an entry that couldn't be inserted into the storage, which is either full or out of memory.
+#[inline]
+fn insertion_error<E: serde::de::Error, K, V, St: MapStorage<K, V>> (len: usize, expected: &dyn serde::de::Expected) -> E {
+    return match St::FIXED_CAPACITY {
+        true => E::invalid_length(len + 1, expected),
+        false => E::custom("memory allocation failed")
+    }
+}
+
+/// Deserializes a [`VecMap`] into the storage returned by `new`, failing if the entries don't fit in it.
+#[inline]
+fn deserialize_vec_map<'de, D, K, V, St, P> (deserializer: D, new: fn() -> St) -> Result<VecMap<K, V, St, P>, D::Error> where
+    D: serde::Deserializer<'de>,
+    K: 'de + Eq + Deserialize<'de>,
+    V: 'de + Deserialize<'de>,
+    St: MapStorage<K, V>,
+    P: OrderPolicy,
+{
+    struct LocalVisitor<'de, K, V, St, P> (fn() -> St, PhantomData<(&'de (K, V), P)>);
+    impl<'de, K: Eq + Deserialize<'de>, V: Deserialize<'de>, St: MapStorage<K, V>, P: OrderPolicy> Visitor<'de> for LocalVisitor<'de, K, V, St, P> {
+        type Value = VecMap<K, V, St, P>;
+
+        #[inline]
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a map that fits in its storage")
+        }
+
+        #[inline]
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
+            let mut storage = (self.0)();
+            storage.reserve_hint(cautious_hint::<K, V>(map.size_hint()));
+            let mut result = VecMap::from_storage(storage).map_err(|_| serde::de::Error::custom("storage has duplicate keys"))?.into_policy();
+            while let Some((key, value)) = map.next_entry()? {
+                if result.checked_insert(key, value).is_err() {
+                    return Err(insertion_error::<_, K, V, St>(result.len(), &self))
+                }
+            }
+            return Ok(result)
+        }
+    }
+
+    return deserializer.deserialize_map(LocalVisitor::<'de, K, V, St, P>(new, PhantomData))
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, St: MapStorage<K, V> + Default, P: OrderPolicy> Deserialize<'de> for VecMap<K, V, St, P> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        return deserialize_vec_map(deserializer, St::default)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Ord + Deserialize<'de>, V: 'de + Deserialize<'de>, St: MapStorage<K, V> + Default> Deserialize<'de> for BinaryMap<K, V, St> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct LocalVisitor<'de, K, V, St> (PhantomData<(&'de (K, V), St)>);
+        impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>, St: MapStorage<K, V> + Default> Visitor<'de> for LocalVisitor<'de, K, V, St> {
+            type Value = BinaryMap<K, V, St>;
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<K: Serialize, V: Serialize, A: Allocator> Serialize for VecMap<K, V, A> {
             #[inline]
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-                let mut ser = serializer.serialize_map(Some(self.len()))?;
-                for (key, value) in self {
-                    ser.serialize_entry(key, value)?;
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a map that fits in its storage")
+            }
+
+            #[inline]
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
+                let mut storage = St::default();
+                storage.reserve_hint(cautious_hint::<K, V>(map.size_hint()));
+                let mut result = BinaryMap::from_storage(storage).map_err(|_| serde::de::Error::custom("storage isn't sorted"))?;
+                while let Some((key, value)) = map.next_entry()? {
+                    if result.checked_insert(key, value).is_err() {
+                        return Err(insertion_error::<_, K, V, St>(result.len(), &self))
+                    }
                 }
-                return ser.end()
+                return Ok(result)
             }
         }
-        
+
+        return deserializer.deserialize_map(LocalVisitor::<'de, K, V, St>(PhantomData))
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "allocator_api")] {
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
         impl<K: Serialize, V: Serialize, A: Allocator> Serialize for BoxMap<K, V, A> {
             #[inline]
@@ -31,7 +142,15 @@ cfg_if::cfg_if! {
         }
 
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<K: Serialize, V: Serialize, A: Allocator> Serialize for BinaryMap<K, V, A> {
+        impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, A: Allocator + Default> Deserialize<'de> for BoxMap<K, V, A> {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                deserialize_vec_map::<_, _, _, _, crate::vec::Unordered>(deserializer, || Vec::new_in(A::default())).map(Into::into)
+            }
+        }
+    } else if #[cfg(feature = "alloc")] {
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<K: Serialize, V: Serialize> Serialize for BoxMap<K, V> {
             #[inline]
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
                 let mut ser = serializer.serialize_map(Some(self.len()))?;
@@ -41,165 +160,306 @@ cfg_if::cfg_if! {
                 return ser.end()
             }
         }
-        
+
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, A: Allocator + Default> Deserialize<'de> for VecMap<K, V, A> {
+        impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>> Deserialize<'de> for BoxMap<K, V> {
             #[inline]
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                struct LocalVisitor<'de, K, V, A> (PhantomData<(&'de (K, V), A)>);
-                impl<'de, K: Eq + Deserialize<'de>, V: Deserialize<'de>, Al: Allocator + Default> Visitor<'de> for LocalVisitor<'de, K, V, Al> {
-                    type Value = VecMap<K, V, Al>;
-        
-                    #[inline]
-                    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
-                        formatter.write_str("a map")
+                VecMap::<K, V>::deserialize(deserializer).map(Into::into)
+            }
+        }
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for the SoA maps, which deserialize by inserting every entry.
+macro_rules! impl_serde_soa {
+    ($(impl[$($g:tt)*] $map:ident<$($p:ident),*> where $bound:path;)+) => {
+        $(
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+            impl<K: Serialize, V: Serialize, St: SoaBuffer<K>, $($g)*> Serialize for $map<K, V, St, $($p),*> {
+                #[inline]
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                    let mut ser = serializer.serialize_map(Some(self.len()))?;
+                    for (key, value) in self {
+                        ser.serialize_entry(key, value)?;
                     }
-        
-                    #[inline]
-                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
-                        let mut result = VecMap::with_capacity_in(map.size_hint().unwrap_or_default(), Al::default());
-                        while let Some((key, value)) = map.next_entry()? {
-                            let _ = result.insert(key, value);
+                    return ser.end()
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+            impl<'de, K: 'de + $bound + Deserialize<'de>, V: 'de + Deserialize<'de>, St: SoaBuffer<K>, $($g)*> Deserialize<'de> for $map<K, V, St, $($p),*> where St::Alloc: Clone + Default {
+                #[inline]
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                    struct LocalVisitor<'de, K, V, St, $($g)*> (PhantomData<(&'de (K, V), St, $($p),*)>);
+                    impl<'de, K: $bound + Deserialize<'de>, V: Deserialize<'de>, St: SoaBuffer<K>, $($g)*> Visitor<'de> for LocalVisitor<'de, K, V, St, $($p),*> where St::Alloc: Clone + Default {
+                        type Value = $map<K, V, St, $($p),*>;
+
+                        #[inline]
+                        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                            formatter.write_str("a map")
+                        }
+
+                        #[inline]
+                        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
+                            let mut result = $map::default();
+                            result.reserve(cautious_hint::<K, V>(map.size_hint()));
+                            while let Some((key, value)) = map.next_entry()? {
+                                let _ = result.insert(key, value);
+                            }
+                            return Ok(result)
                         }
-                        return Ok(result)
                     }
+
+                    return deserializer.deserialize_map(LocalVisitor::<'de, K, V, St, $($p),*>(PhantomData))
                 }
-                
-                return deserializer.deserialize_map(LocalVisitor::<'de, K, V, A>(PhantomData))
             }
+        )+
+    };
+}
+
+impl_serde_soa! {
+    impl[P: OrderPolicy] SoaVecMap<P> where Eq;
+    impl[] SoaBinaryMap<> where Ord;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, St: SoaBuffer<K>> Serialize for SoaBoxMap<K, V, St> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut ser = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self {
+            ser.serialize_entry(key, value)?;
         }
+        return ser.end()
+    }
+}
 
-        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<'de, K: 'de + Ord + Deserialize<'de>, V: 'de + Deserialize<'de>, A: Allocator + Default> Deserialize<'de> for BinaryMap<K, V, A> {
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, St: SoaBuffer<K>> Deserialize<'de> for SoaBoxMap<K, V, St> where St::Alloc: Clone + Default {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        SoaVecMap::<K, V, St>::deserialize(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<T: Serialize, St: MapStorage<T, ()>> Serialize for VecSet<T, St> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut ser = serializer.serialize_seq(Some(self.len()))?;
+        for value in self {
+            ser.serialize_element(value)?;
+        }
+        return ser.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<T: Serialize, St: MapStorage<T, ()>> Serialize for BinarySet<T, St> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut ser = serializer.serialize_seq(Some(self.len()))?;
+        for value in self {
+            ser.serialize_element(value)?;
+        }
+        return ser.end()
+    }
+}
+
+/// Implements `Deserialize` for a set, along with a function that deserializes it into the storage returned by `new`.
+macro_rules! impl_deserialize_set {
+    ($($set:ident($map:ident, $deserialize:ident) where $bound:path),+) => {
+        $(
+            #[cfg(feature = "alloc")]
             #[inline]
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                struct LocalVisitor<'de, K, V, A> (PhantomData<(&'de (K, V), A)>);
-                impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>, Al: Allocator + Default> Visitor<'de> for LocalVisitor<'de, K, V, Al> {
-                    type Value = BinaryMap<K, V, Al>;
-        
+            fn $deserialize<'de, D, T, St> (deserializer: D, new: fn() -> St) -> Result<$set<T, St>, D::Error> where
+                D: serde::Deserializer<'de>,
+                T: 'de + $bound + Deserialize<'de>,
+                St: MapStorage<T, ()>,
+            {
+                struct LocalVisitor<'de, T, St> (fn() -> St, PhantomData<&'de T>);
+                impl<'de, T: $bound + Deserialize<'de>, St: MapStorage<T, ()>> Visitor<'de> for LocalVisitor<'de, T, St> {
+                    type Value = $set<T, St>;
+
                     #[inline]
-                    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
-                        formatter.write_str("a map")
+                    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        formatter.write_str("a sequence that fits in its storage")
                     }
-        
+
                     #[inline]
-                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
-                        let mut result = BinaryMap::with_capacity_in(map.size_hint().unwrap_or_default(), Al::default());
-                        while let Some((key, value)) = map.next_entry()? {
-                            let _ = result.insert(key, value);
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de>, {
+                        let mut storage = (self.0)();
+                        storage.reserve_hint(cautious_hint::<T, ()>(seq.size_hint()));
+                        let mut result = $set::from($map::from_storage(storage).map_err(|_| serde::de::Error::custom("storage isn't a valid set"))?);
+                        while let Some(value) = seq.next_element()? {
+                            if result.checked_insert(value).is_err() {
+                                return Err(insertion_error::<_, T, (), St>(result.len(), &self))
+                            }
                         }
                         return Ok(result)
                     }
                 }
-                
-                return deserializer.deserialize_map(LocalVisitor::<'de, K, V, A>(PhantomData))
-            }
-        }
-        
-        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, A: Allocator + Default> Deserialize<'de> for BoxMap<K, V, A> {
-            #[inline]
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                VecMap::<K, V, A>::deserialize(deserializer).map(Into::into)
+
+                return deserializer.deserialize_seq(LocalVisitor::<'de, T, St>(new, PhantomData))
             }
-        }
-    } else {
-        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<K: Serialize, V: Serialize> Serialize for VecMap<K, V> {
-            #[inline]
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-                let mut ser = serializer.serialize_map(Some(self.len()))?;
-                for (key, value) in self {
-                    ser.serialize_entry(key, value)?;
+
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+            impl<'de, T: 'de + $bound + Deserialize<'de>, St: MapStorage<T, ()> + Default> Deserialize<'de> for $set<T, St> {
+                #[inline]
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                    return $deserialize(deserializer, St::default)
                 }
-                return ser.end()
             }
-        }
-        
+        )+
+    };
+}
+
+impl_deserialize_set!(VecSet(VecMap, deserialize_vec_set) where Eq, BinarySet(BinaryMap, deserialize_binary_set) where Ord);
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "allocator_api")] {
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<K: Serialize, V: Serialize> Serialize for BoxMap<K, V> {
+        impl<T: Serialize, A: Allocator> Serialize for BoxSet<T, A> {
             #[inline]
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-                let mut ser = serializer.serialize_map(Some(self.len()))?;
-                for (key, value) in self {
-                    ser.serialize_entry(key, value)?;
+                let mut ser = serializer.serialize_seq(Some(self.len()))?;
+                for value in self {
+                    ser.serialize_element(value)?;
                 }
                 return ser.end()
             }
         }
 
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<K: Serialize, V: Serialize> Serialize for BinaryMap<K, V> {
+        impl<'de, T: 'de + Eq + Deserialize<'de>, A: Allocator + Default> Deserialize<'de> for BoxSet<T, A> {
             #[inline]
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-                let mut ser = serializer.serialize_map(Some(self.len()))?;
-                for (key, value) in self {
-                    ser.serialize_entry(key, value)?;
-                }
-                return ser.end()
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                deserialize_vec_set(deserializer, || Vec::new_in(A::default())).map(Into::into)
             }
         }
-        
+    } else if #[cfg(feature = "alloc")] {
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>> Deserialize<'de> for VecMap<K, V> {
+        impl<T: Serialize> Serialize for BoxSet<T> {
             #[inline]
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                struct LocalVisitor<'de, K, V> (PhantomData<&'de (K, V)>);
-                impl<'de, K: Eq + Deserialize<'de>, V: Deserialize<'de>> Visitor<'de> for LocalVisitor<'de, K, V> {
-                    type Value = VecMap<K, V>;
-        
-                    #[inline]
-                    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
-                        formatter.write_str("a map")
-                    }
-        
-                    #[inline]
-                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
-                        let mut result = VecMap::with_capacity(map.size_hint().unwrap_or_default());
-                        while let Some((key, value)) = map.next_entry()? {
-                            let _ = result.insert(key, value);
-                        }
-                        return Ok(result)
-                    }
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                let mut ser = serializer.serialize_seq(Some(self.len()))?;
+                for value in self {
+                    ser.serialize_element(value)?;
                 }
-                
-                return deserializer.deserialize_map(LocalVisitor::<'de, K, V>(PhantomData))
+                return ser.end()
             }
         }
 
         #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<'de, K: 'de + Ord + Deserialize<'de>, V: 'de + Deserialize<'de>> Deserialize<'de> for BinaryMap<K, V> {
+        impl<'de, T: 'de + Eq + Deserialize<'de>> Deserialize<'de> for BoxSet<T> {
             #[inline]
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                struct LocalVisitor<'de, K, V> (PhantomData<&'de (K, V)>);
-                impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>> Visitor<'de> for LocalVisitor<'de, K, V> {
-                    type Value = BinaryMap<K, V>;
-        
-                    #[inline]
-                    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
-                        formatter.write_str("a map")
-                    }
-        
-                    #[inline]
-                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de>, {
-                        let mut result = BinaryMap::with_capacity(map.size_hint().unwrap_or_default());
-                        while let Some((key, value)) = map.next_entry()? {
-                            let _ = result.insert(key, value);
-                        }
-                        return Ok(result)
-                    }
-                }
-                
-                return deserializer.deserialize_map(LocalVisitor::<'de, K, V>(PhantomData))
+                VecSet::<T>::deserialize(deserializer).map(Into::into)
             }
         }
-        
-        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-        impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>> Deserialize<'de> for BoxMap<K, V> {
-            #[inline]
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                VecMap::<K, V>::deserialize(deserializer).map(Into::into)
-            }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, const N: usize> Serialize for ArrayMap<K, V, N> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        return VecMap::serialize(self, serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, const N: usize> Deserialize<'de> for ArrayMap<K, V, N> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        return VecMap::deserialize(deserializer).map(ArrayMap::from)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, const N: usize> Serialize for ArrayBinaryMap<K, V, N> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        return BinaryMap::serialize(self, serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Ord + Deserialize<'de>, V: 'de + Deserialize<'de>, const N: usize> Deserialize<'de> for ArrayBinaryMap<K, V, N> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        return BinaryMap::deserialize(deserializer).map(ArrayBinaryMap::from)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize> Serialize for SliceMap<'_, K, V> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut ser = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self {
+            ser.serialize_entry(key, value)?;
         }
+        return ser.end()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize> Serialize for SliceMapMut<'_, K, V> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        self.as_map().serialize(serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize> Serialize for StaticBinaryMap<K, V> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        self.as_map().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, const N: usize, H: HeapStorage<K, V>> Serialize for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        return VecMap::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Eq + Deserialize<'de>, V: 'de + Deserialize<'de>, const N: usize, H: HeapStorage<K, V> + Default> Deserialize<'de> for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        return VecMap::deserialize(deserializer).map(SmallVecMap::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<K: Serialize, V: Serialize, const N: usize, H: HeapStorage<K, V>> Serialize for SmallBinaryMap<K, V, N, H> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        return BinaryMap::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, K: 'de + Ord + Deserialize<'de>, V: 'de + Deserialize<'de>, const N: usize, H: HeapStorage<K, V> + Default> Deserialize<'de> for SmallBinaryMap<K, V, N, H> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        return BinaryMap::deserialize(deserializer).map(SmallBinaryMap::from)
     }
 }
@@ -0,0 +1,250 @@
+use super::*;
+use crate::{storage::{HeapStorage, MapStorage}, binary::{cmp_to_range, BinaryMap, Entry}};
+use docfg::docfg;
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use crate::heap::{collections::TryReserveError, vec::Vec};
+use core::{borrow::Borrow, fmt::Debug, ops::RangeBounds};
+
+/// A set backed by a [`BinaryMap`], which keeps its elements sorted and finds them through binary search.
+///
+/// Like the map, it's generic over the [`MapStorage`] of its elements, paired with a `()` value.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct BinarySet<T, S = Vec<(T, ())>> {
+    pub(super) inner: BinaryMap<T, (), S>,
+}
+
+impl<T> BinarySet<T> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self { inner: BinaryMap::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity (capacity: usize) -> Self {
+        return Self { inner: BinaryMap::with_capacity(capacity) }
+    }
+}
+
+#[docfg(feature = "allocator_api")]
+impl<T, A: Allocator> BinarySet<T, Vec<(T, ()), A>> {
+    #[inline]
+    pub const fn new_in (alloc: A) -> Self {
+        return Self { inner: BinaryMap::new_in(alloc) }
+    }
+
+    #[inline]
+    pub fn with_capacity_in (capacity: usize, alloc: A) -> Self {
+        return Self { inner: BinaryMap::with_capacity_in(capacity, alloc) }
+    }
+}
+
+impl<T, S: MapStorage<T, ()>> BinarySet<T, S> {
+    #[inline]
+    pub fn len (&self) -> usize {
+        return self.inner.len()
+    }
+
+    #[inline]
+    pub fn is_empty (&self) -> bool {
+        return self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn clear (&mut self) {
+        self.inner.clear()
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool> (&mut self, mut f: F) {
+        self.inner.retain(|x, _| f(x))
+    }
+
+    /// Iterates the elements of the set in ascending order.
+    #[inline]
+    pub fn iter (&self) -> Iter<'_, T> {
+        return Iter(self.inner.keys())
+    }
+
+    /// Returns the smallest element of the set.
+    #[inline]
+    pub fn first (&self) -> Option<&T> {
+        let (value, _) = self.inner.first_key_value()?;
+        return Some(value)
+    }
+
+    /// Returns the largest element of the set.
+    #[inline]
+    pub fn last (&self) -> Option<&T> {
+        let (value, _) = self.inner.last_key_value()?;
+        return Some(value)
+    }
+
+    #[inline]
+    pub fn as_map (&self) -> &BinaryMap<T, (), S> {
+        return &self.inner
+    }
+
+    #[inline]
+    pub fn into_map (self) -> BinaryMap<T, (), S> {
+        return self.inner
+    }
+}
+
+impl<T: Ord, S: MapStorage<T, ()>> BinarySet<T, S> {
+    #[inline]
+    pub fn contains<Q: ?Sized + Ord> (&self, value: &Q) -> bool where T: Borrow<Q> {
+        return self.inner.contains_key(value)
+    }
+
+    #[inline]
+    pub fn get<Q: ?Sized + Ord> (&self, value: &Q) -> Option<&T> where T: Borrow<Q> {
+        let (value, _) = self.inner.get_key_value(value)?;
+        return Some(value)
+    }
+
+    /// Adds a value to the set, returning whether it was newly inserted.
+    /// Returns the value back if it's new and the storage is full.
+    #[inline]
+    pub fn checked_insert (&mut self, value: T) -> Result<bool, T> {
+        return match self.inner.checked_insert(value, ()) {
+            Ok(prev) => Ok(prev.is_none()),
+            Err((value, _)) => Err(value),
+        }
+    }
+
+    #[inline]
+    pub fn remove<Q: ?Sized + Ord> (&mut self, value: &Q) -> bool where T: Borrow<Q> {
+        return self.inner.remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set that is equal to the given one, if any.
+    #[inline]
+    pub fn take<Q: ?Sized + Ord> (&mut self, value: &Q) -> Option<T> where T: Borrow<Q> {
+        let (value, _) = self.inner.remove_entry(value)?;
+        return Some(value)
+    }
+
+    /// Iterates the elements of the set that fall inside the range, in ascending order.
+    #[inline]
+    pub fn range<Q: ?Sized + Ord, R: RangeBounds<Q>> (&self, range: R) -> Iter<'_, T> where T: Borrow<Q> {
+        let (_, iter) = self.inner.range_by(|x| cmp_to_range(x.borrow(), &range));
+        return Iter(crate::vec::Keys(iter.0))
+    }
+}
+
+impl<T, S: HeapStorage<T, ()>> BinarySet<T, S> {
+    #[inline]
+    pub fn capacity (&self) -> usize {
+        return self.inner.capacity()
+    }
+
+    #[inline]
+    pub fn reserve (&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    #[inline]
+    pub fn shrink_to_fit (&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<T: Ord, S: HeapStorage<T, ()>> BinarySet<T, S> {
+    /// Adds a value to the set, returning whether it was newly inserted.
+    /// If an equal value is already present, it is left untouched.
+    #[inline]
+    pub fn insert (&mut self, value: T) -> bool {
+        return match self.inner.entry(value) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(());
+                true
+            }
+        }
+    }
+
+    /// Adds a value to the set, replacing and returning the equal value already present, if any.
+    #[inline]
+    pub fn replace (&mut self, value: T) -> Option<T> {
+        return match self.inner.entry(value) {
+            Entry::Occupied(entry) => Some(entry.replace_key()),
+            Entry::Vacant(entry) => {
+                entry.insert(());
+                None
+            }
+        }
+    }
+}
+
+impl<T, S> From<BinaryMap<T, (), S>> for BinarySet<T, S> {
+    #[inline]
+    fn from(inner: BinaryMap<T, (), S>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, S: HeapStorage<T, ()>> IntoIterator for BinarySet<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.inner.into_iter().map(element as fn(_) -> _))
+    }
+}
+
+impl<'a, T, S: MapStorage<T, ()>> IntoIterator for &'a BinarySet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        BinarySet::iter(self)
+    }
+}
+
+impl<T: Ord, S: HeapStorage<T, ()>> Extend<T> for BinarySet<T, S> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            let _ = self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord, S: HeapStorage<T, ()>> FromIterator<T> for BinarySet<T, S> where S::Alloc: Default {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>> (iter: I) -> Self {
+        Self { inner: iter.into_iter().map(|x| (x, ())).collect() }
+    }
+}
+
+impl<T, S: MapStorage<T, ()> + Default> Default for BinarySet<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self { inner: BinaryMap::default() }
+    }
+}
+
+impl<T: Debug, S: MapStorage<T, ()>> Debug for BinarySet<T, S> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, S: MapStorage<T, ()>, R: MapStorage<T, ()>> PartialEq<BinarySet<T, R>> for BinarySet<T, S> {
+    #[inline]
+    fn eq(&self, other: &BinarySet<T, R>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, S: MapStorage<T, ()>> Eq for BinarySet<T, S> {}
@@ -0,0 +1,243 @@
+macro_rules! impl_all {
+    ($(where $($trait:path),+ =>)? { $($t:tt)* }) => {
+        #[cfg(feature = "allocator_api")]
+        impl<T, A: Allocator> BoxSet<T, A> $(where T: $($trait+)*)? {
+            $($t)*
+        }
+
+        #[cfg(not(feature = "allocator_api"))]
+        impl<T> BoxSet<T> $(where T: $($trait+)*)? {
+            $($t)*
+        }
+    };
+}
+
+use super::*;
+use crate::{r#box::BoxMap, vec::VecMap};
+#[cfg(feature = "allocator_api")]
+use crate::heap::vec::Vec;
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use core::{borrow::Borrow, fmt::Debug};
+
+/// A fixed-size set backed by a [`BoxMap`], built out of a [`VecSet`] or an iterator.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct BoxSet<T, #[cfg(feature = "allocator_api")] A: Allocator = Global> {
+    #[cfg(feature = "allocator_api")]
+    inner: BoxMap<T, (), A>,
+    #[cfg(not(feature = "allocator_api"))]
+    inner: BoxMap<T, ()>,
+}
+
+impl_all! {{
+    #[inline]
+    pub fn len (&self) -> usize {
+        return self.inner.len()
+    }
+
+    #[inline]
+    pub fn is_empty (&self) -> bool {
+        return self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'_, T> {
+        return Iter(crate::vec::Keys(self.inner.keys().0))
+    }
+}}
+
+impl_all! {
+    where Eq => {
+        #[inline]
+        pub fn contains<Q: ?Sized + Eq> (&self, value: &Q) -> bool where T: Borrow<Q> {
+            return self.inner.contains_key(value)
+        }
+
+        #[inline]
+        pub fn get<Q: ?Sized + Eq> (&self, value: &Q) -> Option<&T> where T: Borrow<Q> {
+            let (value, _) = self.inner.get_key_value(value)?;
+            return Some(value)
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "allocator_api")] {
+        impl<T, A: Allocator> BoxSet<T, A> {
+            #[inline]
+            pub fn retain<F: FnMut(&T) -> bool> (self, mut f: F) -> Self {
+                return Self { inner: self.inner.retain(|x, _| f(x)) }
+            }
+
+            #[inline]
+            pub fn as_map (&self) -> &BoxMap<T, (), A> {
+                return &self.inner
+            }
+
+            #[inline]
+            pub fn into_map (self) -> BoxMap<T, (), A> {
+                return self.inner
+            }
+        }
+
+        impl<T, A: Allocator> From<BoxMap<T, (), A>> for BoxSet<T, A> {
+            #[inline]
+            fn from(inner: BoxMap<T, (), A>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T, A: Allocator> From<VecSet<T, Vec<(T, ()), A>>> for BoxSet<T, A> {
+            #[inline]
+            fn from(value: VecSet<T, Vec<(T, ()), A>>) -> Self {
+                Self { inner: value.inner.into() }
+            }
+        }
+
+        impl<T, A: Allocator> From<BoxSet<T, A>> for VecSet<T, Vec<(T, ()), A>> {
+            #[inline]
+            fn from(value: BoxSet<T, A>) -> Self {
+                Self { inner: VecMap::from(value.inner) }
+            }
+        }
+
+        impl<T, A: Allocator> IntoIterator for BoxSet<T, A> {
+            type Item = T;
+            type IntoIter = IntoIter<T, Vec<(T, ()), A>>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIter(self.inner.into_iter().map(element as fn(_) -> _))
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a BoxSet<T, A> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                BoxSet::iter(self)
+            }
+        }
+
+        impl<T: Eq, A: Allocator + Default> FromIterator<T> for BoxSet<T, A> {
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = T>> (iter: I) -> Self {
+                VecSet::<T, Vec<(T, ()), A>>::from_iter(iter).into()
+            }
+        }
+
+        impl<T, A: Allocator + Default> Default for BoxSet<T, A> {
+            #[inline]
+            fn default() -> Self {
+                Self { inner: BoxMap::default() }
+            }
+        }
+
+        impl<T: Debug, A: Allocator> Debug for BoxSet<T, A> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: Eq, A: Allocator, B: Allocator> PartialEq<BoxSet<T, B>> for BoxSet<T, A> {
+            #[inline]
+            fn eq(&self, other: &BoxSet<T, B>) -> bool {
+                self.len() == other.len() && self.iter().all(|x| other.contains(x))
+            }
+        }
+
+        impl<T: Eq, A: Allocator> Eq for BoxSet<T, A> {}
+    } else {
+        impl<T> BoxSet<T> {
+            #[inline]
+            pub fn retain<F: FnMut(&T) -> bool> (self, mut f: F) -> Self {
+                return Self { inner: self.inner.retain(|x, _| f(x)) }
+            }
+
+            #[inline]
+            pub fn as_map (&self) -> &BoxMap<T, ()> {
+                return &self.inner
+            }
+
+            #[inline]
+            pub fn into_map (self) -> BoxMap<T, ()> {
+                return self.inner
+            }
+        }
+
+        impl<T> From<BoxMap<T, ()>> for BoxSet<T> {
+            #[inline]
+            fn from(inner: BoxMap<T, ()>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T> From<VecSet<T>> for BoxSet<T> {
+            #[inline]
+            fn from(value: VecSet<T>) -> Self {
+                Self { inner: value.inner.into() }
+            }
+        }
+
+        impl<T> From<BoxSet<T>> for VecSet<T> {
+            #[inline]
+            fn from(value: BoxSet<T>) -> Self {
+                Self { inner: VecMap::from(value.inner) }
+            }
+        }
+
+        impl<T> IntoIterator for BoxSet<T> {
+            type Item = T;
+            type IntoIter = IntoIter<T>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIter(self.inner.into_iter().map(element as fn(_) -> _))
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a BoxSet<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                BoxSet::iter(self)
+            }
+        }
+
+        impl<T: Eq> FromIterator<T> for BoxSet<T> {
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = T>> (iter: I) -> Self {
+                VecSet::<T>::from_iter(iter).into()
+            }
+        }
+
+        impl<T> Default for BoxSet<T> {
+            #[inline]
+            fn default() -> Self {
+                Self { inner: BoxMap::default() }
+            }
+        }
+
+        impl<T: Debug> Debug for BoxSet<T> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: Eq> PartialEq for BoxSet<T> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.len() == other.len() && self.iter().all(|x| other.contains(x))
+            }
+        }
+
+        impl<T: Eq> Eq for BoxSet<T> {}
+    }
+}
@@ -0,0 +1,25 @@
+use crate::storage::HeapStorage;
+use core::iter::Map;
+
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Iter<'a, T: 'a> (pub(super) crate::vec::Keys<'a, T, ()>);
+
+#[repr(transparent)]
+pub struct IntoIter<T, S: HeapStorage<T, ()> = crate::heap::vec::Vec<(T, ())>> (pub(super) Map<S::IntoIter, fn((T, ())) -> T>);
+
+impl<T, S: HeapStorage<T, ()>> core::fmt::Debug for IntoIter<T, S> where S::IntoIter: core::fmt::Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.0).finish()
+    }
+}
+
+forward_iter! {
+    impl['a, T] Iter<'a, T> => &'a T;
+    impl[T, S: HeapStorage<T, ()>] IntoIter<T, S> => T;
+}
+
+#[inline]
+pub(super) fn element<T> ((element, _): (T, ())) -> T {
+    return element
+}
@@ -0,0 +1,3 @@
+//! Sets built on top of the maps, storing their elements as keys with a `()` value.
+
+flat_mod! { iter, vec, binary, r#box }
@@ -0,0 +1,228 @@
+use super::*;
+use crate::{storage::{HeapStorage, MapStorage}, vec::{Entry, VecMap}};
+use docfg::docfg;
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use crate::heap::{collections::TryReserveError, vec::Vec};
+use core::{borrow::Borrow, fmt::Debug};
+
+/// A set backed by a [`VecMap`], with linear-time lookups and no ordering guarantees.
+///
+/// Like the map, it's generic over the [`MapStorage`] of its elements, paired with a `()` value.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct VecSet<T, S = Vec<(T, ())>> {
+    pub(super) inner: VecMap<T, (), S>,
+}
+
+impl<T> VecSet<T> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self { inner: VecMap::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity (capacity: usize) -> Self {
+        return Self { inner: VecMap::with_capacity(capacity) }
+    }
+}
+
+#[docfg(feature = "allocator_api")]
+impl<T, A: Allocator> VecSet<T, Vec<(T, ()), A>> {
+    #[inline]
+    pub const fn new_in (alloc: A) -> Self {
+        return Self { inner: VecMap::new_in(alloc) }
+    }
+
+    #[inline]
+    pub fn with_capacity_in (capacity: usize, alloc: A) -> Self {
+        return Self { inner: VecMap::with_capacity_in(capacity, alloc) }
+    }
+}
+
+impl<T, S: MapStorage<T, ()>> VecSet<T, S> {
+    #[inline]
+    pub fn len (&self) -> usize {
+        return self.inner.len()
+    }
+
+    #[inline]
+    pub fn is_empty (&self) -> bool {
+        return self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn clear (&mut self) {
+        self.inner.clear()
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool> (&mut self, mut f: F) {
+        self.inner.retain(|x, _| f(x))
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'_, T> {
+        return Iter(self.inner.keys())
+    }
+
+    #[inline]
+    pub fn as_map (&self) -> &VecMap<T, (), S> {
+        return &self.inner
+    }
+
+    #[inline]
+    pub fn into_map (self) -> VecMap<T, (), S> {
+        return self.inner
+    }
+}
+
+impl<T: Eq, S: MapStorage<T, ()>> VecSet<T, S> {
+    #[inline]
+    pub fn contains<Q: ?Sized + Eq> (&self, value: &Q) -> bool where T: Borrow<Q> {
+        return self.inner.contains_key(value)
+    }
+
+    #[inline]
+    pub fn get<Q: ?Sized + Eq> (&self, value: &Q) -> Option<&T> where T: Borrow<Q> {
+        let (value, _) = self.inner.get_key_value(value)?;
+        return Some(value)
+    }
+
+    /// Adds a value to the set, returning whether it was newly inserted.
+    /// Returns the value back if it's new and the storage is full.
+    #[inline]
+    pub fn checked_insert (&mut self, value: T) -> Result<bool, T> {
+        return match self.inner.checked_insert(value, ()) {
+            Ok(prev) => Ok(prev.is_none()),
+            Err((value, _)) => Err(value),
+        }
+    }
+
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq> (&mut self, value: &Q) -> bool where T: Borrow<Q> {
+        return self.inner.remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set that is equal to the given one, if any.
+    #[inline]
+    pub fn take<Q: ?Sized + Eq> (&mut self, value: &Q) -> Option<T> where T: Borrow<Q> {
+        let (value, _) = self.inner.remove_entry(value)?;
+        return Some(value)
+    }
+}
+
+impl<T, S: HeapStorage<T, ()>> VecSet<T, S> {
+    #[inline]
+    pub fn capacity (&self) -> usize {
+        return self.inner.capacity()
+    }
+
+    #[inline]
+    pub fn reserve (&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    #[inline]
+    pub fn shrink_to_fit (&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<T: Eq, S: HeapStorage<T, ()>> VecSet<T, S> {
+    /// Adds a value to the set, returning whether it was newly inserted.
+    /// If an equal value is already present, it is left untouched.
+    #[inline]
+    pub fn insert (&mut self, value: T) -> bool {
+        return match self.inner.entry(value) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(());
+                true
+            }
+        }
+    }
+
+    /// Adds a value to the set, replacing and returning the equal value already present, if any.
+    #[inline]
+    pub fn replace (&mut self, value: T) -> Option<T> {
+        return match self.inner.entry(value) {
+            Entry::Occupied(entry) => Some(entry.replace_key()),
+            Entry::Vacant(entry) => {
+                entry.insert(());
+                None
+            }
+        }
+    }
+}
+
+impl<T, S> From<VecMap<T, (), S>> for VecSet<T, S> {
+    #[inline]
+    fn from(inner: VecMap<T, (), S>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, S: HeapStorage<T, ()>> IntoIterator for VecSet<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.inner.into_iter().map(element as fn(_) -> _))
+    }
+}
+
+impl<'a, T, S: MapStorage<T, ()>> IntoIterator for &'a VecSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        VecSet::iter(self)
+    }
+}
+
+impl<T: Eq, S: HeapStorage<T, ()>> Extend<T> for VecSet<T, S> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            let _ = self.insert(value);
+        }
+    }
+}
+
+impl<T: Eq, S: HeapStorage<T, ()>> FromIterator<T> for VecSet<T, S> where S::Alloc: Default {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>> (iter: I) -> Self {
+        Self { inner: iter.into_iter().map(|x| (x, ())).collect() }
+    }
+}
+
+impl<T, S: MapStorage<T, ()> + Default> Default for VecSet<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self { inner: VecMap::default() }
+    }
+}
+
+impl<T: Debug, S: MapStorage<T, ()>> Debug for VecSet<T, S> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Eq, S: MapStorage<T, ()>, R: MapStorage<T, ()>> PartialEq<VecSet<T, R>> for VecSet<T, S> {
+    #[inline]
+    fn eq(&self, other: &VecSet<T, R>) -> bool {
+        self.len() == other.len() && self.iter().all(|x| other.contains(x))
+    }
+}
+
+impl<T: Eq, S: MapStorage<T, ()>> Eq for VecSet<T, S> {}
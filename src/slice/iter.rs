@@ -0,0 +1,284 @@
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Keys<'a, K: 'a, V: 'a> (pub(crate) core::slice::Iter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, _) = self.0.next()?;
+        return Some(key)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (key, _) = self.0.nth(n)?;
+        return Some(key)
+    }
+
+    #[inline]
+    fn count(self) -> usize where Self: Sized, {
+        self.0.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> where Self: Sized, {
+        let (key, _) = self.0.last()?;
+        return Some(key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, _) = self.0.next_back()?;
+        return Some(key)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (key, _) = self.0.nth_back(n)?;
+        return Some(key)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Values<'a, K: 'a, V: 'a> (pub(crate) core::slice::Iter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, values) = self.0.next()?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (_, values) = self.0.nth(n)?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn count(self) -> usize where Self: Sized, {
+        self.0.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> where Self: Sized, {
+        let (_, values) = self.0.last()?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, values) = self.0.next_back()?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (_, values) = self.0.nth_back(n)?;
+        return Some(values)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct ValuesMut<'a, K: 'a, V: 'a> (pub(crate) core::slice::IterMut<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, values) = self.0.next()?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (_, values) = self.0.nth(n)?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn count(self) -> usize where Self: Sized, {
+        self.0.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> where Self: Sized, {
+        let (_, values) = self.0.last()?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, values) = self.0.next_back()?;
+        return Some(values)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (_, values) = self.0.nth_back(n)?;
+        return Some(values)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Iter<'a, K: 'a, V: 'a> (pub(crate) core::slice::Iter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.0.next()?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (key, value) = self.0.nth(n)?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn count(self) -> usize where Self: Sized, {
+        self.0.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> where Self: Sized, {
+        let (key, value) = self.0.last()?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.0.next_back()?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (key, value) = self.0.nth_back(n)?;
+        return Some((key, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct IterMut<'a, K: 'a, V: 'a> (pub(crate) core::slice::IterMut<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.0.next()?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (key, value) = self.0.nth(n)?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn count(self) -> usize where Self: Sized, {
+        self.0.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> where Self: Sized, {
+        let (key, value) = self.0.last()?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.0.next_back()?;
+        return Some((key, value))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (key, value) = self.0.nth_back(n)?;
+        return Some((key, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
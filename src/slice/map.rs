@@ -0,0 +1,343 @@
+use super::*;
+use crate::search::SearchKey;
+use core::{borrow::Borrow, fmt::Debug, ops::Index};
+
+/// A read-only map over a slice of entries.
+///
+/// Keys are looked up linearly, as in [`VecMap`](crate::vec::VecMap), or through binary search with the `binary_*`
+/// methods, as in [`BinaryMap`](crate::binary::BinaryMap). The latter require the entries to be sorted by key
+/// (see [`is_sorted`](SliceMap::is_sorted)), and return unspecified results otherwise.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SliceMap<'a, K, V> {
+    inner: &'a [(K, V)],
+}
+
+impl<'a, K, V> SliceMap<'a, K, V> {
+    #[inline]
+    pub const fn new (entries: &'a [(K, V)]) -> Self {
+        return Self { inner: entries }
+    }
+
+    #[inline]
+    pub const fn len (&self) -> usize {
+        return self.inner.len()
+    }
+
+    #[inline]
+    pub const fn is_empty (&self) -> bool {
+        return self.inner.is_empty()
+    }
+
+    #[inline]
+    pub const fn as_slice (&self) -> &'a [(K, V)] {
+        return self.inner
+    }
+
+    #[inline]
+    pub fn get_index (&self, idx: usize) -> Option<(&'a K, &'a V)> {
+        let (k, v) = self.inner.get(idx)?;
+        return Some((k, v))
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'a, K, V> {
+        return Iter(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn keys (&self) -> Keys<'a, K, V> {
+        return Keys(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn values (&self) -> Values<'a, K, V> {
+        return Values(self.inner.iter())
+    }
+}
+
+impl<'a, K: Eq, V> SliceMap<'a, K, V> {
+    #[inline]
+    pub fn get_index_of<Q: ?Sized + Eq> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return Q::position(self.inner, key, |(k, _)| k)
+    }
+
+    #[inline]
+    pub fn get<Q: ?Sized + Eq> (&self, key: &Q) -> Option<&'a V> where K: Borrow<Q> {
+        let (_, v) = self.get_key_value(key)?;
+        return Some(v)
+    }
+
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(&'a K, &'a V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return self.get_index(idx)
+    }
+
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Eq> (&self, key: &Q) -> bool where K: Borrow<Q> {
+        return self.get_index_of(key).is_some()
+    }
+}
+
+impl<'a, K: Ord, V> SliceMap<'a, K, V> {
+    /// Returns `true` if the keys are sorted and unique, as required by the `binary_*` methods.
+    #[inline]
+    pub fn is_sorted (&self) -> bool {
+        return self.inner.windows(2).all(|x| x[0].0 < x[1].0)
+    }
+
+    #[inline]
+    pub fn binary_get_index_of<Q: ?Sized + Ord> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return self.inner.binary_search_by(|(k, _)| k.borrow().cmp(key)).ok()
+    }
+
+    #[inline]
+    pub fn binary_get<Q: ?Sized + Ord> (&self, key: &Q) -> Option<&'a V> where K: Borrow<Q> {
+        let (_, v) = self.binary_get_key_value(key)?;
+        return Some(v)
+    }
+
+    #[inline]
+    pub fn binary_get_key_value<Q: ?Sized + Ord> (&self, key: &Q) -> Option<(&'a K, &'a V)> where K: Borrow<Q> {
+        let idx = self.binary_get_index_of(key)?;
+        return self.get_index(idx)
+    }
+
+    #[inline]
+    pub fn binary_contains_key<Q: ?Sized + Ord> (&self, key: &Q) -> bool where K: Borrow<Q> {
+        return self.binary_get_index_of(key).is_some()
+    }
+}
+
+/// A map over a mutable slice of entries, whose values can be modified but whose keys can't.
+///
+/// Lookups work as in [`SliceMap`].
+#[derive(PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SliceMapMut<'a, K, V> {
+    inner: &'a mut [(K, V)],
+}
+
+impl<'a, K, V> SliceMapMut<'a, K, V> {
+    #[inline]
+    pub const fn new (entries: &'a mut [(K, V)]) -> Self {
+        return Self { inner: entries }
+    }
+
+    #[inline]
+    pub const fn len (&self) -> usize {
+        return self.inner.len()
+    }
+
+    #[inline]
+    pub const fn is_empty (&self) -> bool {
+        return self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn as_slice (&self) -> &[(K, V)] {
+        return self.inner
+    }
+
+    /// Returns a read-only view of the map.
+    #[inline]
+    pub fn as_map (&self) -> SliceMap<'_, K, V> {
+        return SliceMap::new(self.inner)
+    }
+
+    #[inline]
+    pub fn get_index (&self, idx: usize) -> Option<(&K, &V)> {
+        let (k, v) = self.inner.get(idx)?;
+        return Some((k, v))
+    }
+
+    #[inline]
+    pub fn get_index_mut (&mut self, idx: usize) -> Option<(&K, &mut V)> {
+        let (k, v) = self.inner.get_mut(idx)?;
+        return Some((k, v))
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'_, K, V> {
+        return Iter(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn iter_mut (&mut self) -> IterMut<'_, K, V> {
+        return IterMut(self.inner.iter_mut())
+    }
+
+    #[inline]
+    pub fn keys (&self) -> Keys<'_, K, V> {
+        return Keys(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn values (&self) -> Values<'_, K, V> {
+        return Values(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn values_mut (&mut self) -> ValuesMut<'_, K, V> {
+        return ValuesMut(self.inner.iter_mut())
+    }
+}
+
+impl<'a, K: Eq, V> SliceMapMut<'a, K, V> {
+    #[inline]
+    pub fn get_index_of<Q: ?Sized + Eq> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return self.as_map().get_index_of(key)
+    }
+
+    #[inline]
+    pub fn get<Q: ?Sized + Eq> (&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        return self.as_map().get(key)
+    }
+
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        return self.as_map().get_key_value(key)
+    }
+
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(&mut self.inner.get_unchecked_mut(idx).1) }
+    }
+
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Eq> (&self, key: &Q) -> bool where K: Borrow<Q> {
+        return self.get_index_of(key).is_some()
+    }
+}
+
+impl<'a, K: Ord, V> SliceMapMut<'a, K, V> {
+    /// Returns `true` if the keys are sorted and unique, as required by the `binary_*` methods.
+    #[inline]
+    pub fn is_sorted (&self) -> bool {
+        return self.as_map().is_sorted()
+    }
+
+    #[inline]
+    pub fn binary_get_index_of<Q: ?Sized + Ord> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return self.as_map().binary_get_index_of(key)
+    }
+
+    #[inline]
+    pub fn binary_get<Q: ?Sized + Ord> (&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        return self.as_map().binary_get(key)
+    }
+
+    #[inline]
+    pub fn binary_get_key_value<Q: ?Sized + Ord> (&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        return self.as_map().binary_get_key_value(key)
+    }
+
+    #[inline]
+    pub fn binary_get_mut<Q: ?Sized + Ord> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let idx = self.binary_get_index_of(key)?;
+        return unsafe { Some(&mut self.inner.get_unchecked_mut(idx).1) }
+    }
+
+    #[inline]
+    pub fn binary_contains_key<Q: ?Sized + Ord> (&self, key: &Q) -> bool where K: Borrow<Q> {
+        return self.binary_get_index_of(key).is_some()
+    }
+}
+
+impl<'a, K, V> From<&'a [(K, V)]> for SliceMap<'a, K, V> {
+    #[inline]
+    fn from(value: &'a [(K, V)]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'a, K, V> From<&'a mut [(K, V)]> for SliceMapMut<'a, K, V> {
+    #[inline]
+    fn from(value: &'a mut [(K, V)]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'a, K, V> IntoIterator for SliceMap<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &SliceMap<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for SliceMapMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut(self.inner.iter_mut())
+    }
+}
+
+impl<'a, 'b, K, V> IntoIterator for &'b SliceMapMut<'a, K, V> {
+    type Item = (&'b K, &'b V);
+    type IntoIter = Iter<'b, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, K, V> IntoIterator for &'b mut SliceMapMut<'a, K, V> {
+    type Item = (&'b K, &'b mut V);
+    type IntoIter = IterMut<'b, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for SliceMap<'_, K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for SliceMapMut<'_, K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<Q: ?Sized + Eq, K: Eq + Borrow<Q>, V> Index<&Q> for SliceMap<'_, K, V> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
+    }
+}
+
+impl<Q: ?Sized + Eq, K: Eq + Borrow<Q>, V> Index<&Q> for SliceMapMut<'_, K, V> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
+    }
+}
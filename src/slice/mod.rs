@@ -0,0 +1,4 @@
+//! Read-only maps over a slice of entries provided by the caller. These never allocate, and are available
+//! without the `alloc` feature.
+
+flat_mod! { iter, map, r#static }
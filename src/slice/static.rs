@@ -0,0 +1,241 @@
+use super::*;
+use core::{cmp::Ordering, fmt::Debug};
+
+/// A read-only map over a `'static` slice of entries sorted by key, meant for tables known at compile time.
+///
+/// For integer, `char` and `&str` keys, [`new`](StaticBinaryMap::new) checks that the keys are sorted and unique,
+/// failing to compile if it's evaluated at compile time, and the lookups are `const fn`s.
+/// Other keys can be looked up through [`search_by`](StaticBinaryMap::search_by) or [`as_map`](StaticBinaryMap::as_map).
+/// See also [`static_binary_map!`](crate::static_binary_map).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct StaticBinaryMap<K: 'static, V: 'static> {
+    inner: &'static [(K, V)],
+}
+
+impl<K: 'static, V: 'static> StaticBinaryMap<K, V> {
+    /// Builds a map over the entries, without checking that their keys are sorted and unique.
+    ///
+    /// # Safety
+    /// The keys must be sorted and unique.
+    #[inline]
+    pub const unsafe fn from_sorted_unchecked (entries: &'static [(K, V)]) -> Self {
+        return Self { inner: entries }
+    }
+
+    #[inline]
+    pub const fn len (&self) -> usize {
+        return self.inner.len()
+    }
+
+    #[inline]
+    pub const fn is_empty (&self) -> bool {
+        return self.inner.is_empty()
+    }
+
+    #[inline]
+    pub const fn as_slice (&self) -> &'static [(K, V)] {
+        return self.inner
+    }
+
+    #[inline]
+    pub const fn as_map (&self) -> SliceMap<'static, K, V> {
+        return SliceMap::new(self.inner)
+    }
+
+    #[inline]
+    pub const fn get_index (&self, idx: usize) -> Option<(&'static K, &'static V)> {
+        if idx >= self.inner.len() {
+            return None
+        }
+        let (k, v) = &self.inner[idx];
+        return Some((k, v))
+    }
+
+    #[inline]
+    pub const fn first_key_value (&self) -> Option<(&'static K, &'static V)> {
+        return self.get_index(0)
+    }
+
+    #[inline]
+    pub const fn last_key_value (&self) -> Option<(&'static K, &'static V)> {
+        return match self.inner.len() {
+            0 => None,
+            len => self.get_index(len - 1),
+        }
+    }
+
+    /// Returns the entry for which the comparator returns [`Ordering::Equal`], along with its index.
+    /// The comparator must be consistent with the ordering of the keys, as in [`slice::binary_search_by`].
+    #[inline]
+    pub fn search_by<F: FnMut(&K) -> Ordering> (&self, mut f: F) -> Option<(usize, &'static K, &'static V)> {
+        let idx = self.inner.binary_search_by(|(k, _)| f(k)).ok()?;
+        let (k, v) = &self.inner[idx];
+        return Some((idx, k, v))
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'static, K, V> {
+        return Iter(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn keys (&self) -> Keys<'static, K, V> {
+        return Keys(self.inner.iter())
+    }
+
+    #[inline]
+    pub fn values (&self) -> Values<'static, K, V> {
+        return Values(self.inner.iter())
+    }
+}
+
+macro_rules! impl_const {
+    ($($t:ty),+ => |$a:ident, $b:ident| $cmp:expr) => {
+        $(
+            impl<V: 'static> StaticBinaryMap<$t, V> {
+                #[inline]
+                const fn cmp ($a: $t, $b: $t) -> Ordering {
+                    return $cmp
+                }
+
+                /// Builds a map over the entries, panicking if their keys aren't sorted and unique.
+                #[inline]
+                pub const fn new (entries: &'static [($t, V)]) -> Self {
+                    let mut i = 1;
+                    while i < entries.len() {
+                        assert!(
+                            matches!(Self::cmp(entries[i - 1].0, entries[i].0), Ordering::Less),
+                            "the keys of a static binary map must be sorted and unique"
+                        );
+                        i += 1;
+                    }
+                    return Self { inner: entries }
+                }
+
+                #[inline]
+                pub const fn get_index_of (&self, key: $t) -> Option<usize> {
+                    let (mut lo, mut hi) = (0, self.inner.len());
+                    while lo < hi {
+                        let mid = lo + (hi - lo) / 2;
+                        match Self::cmp(self.inner[mid].0, key) {
+                            Ordering::Less => lo = mid + 1,
+                            Ordering::Greater => hi = mid,
+                            Ordering::Equal => return Some(mid),
+                        }
+                    }
+                    return None
+                }
+
+                #[inline]
+                pub const fn get (&self, key: $t) -> Option<&'static V> {
+                    return match self.get_index_of(key) {
+                        Some(idx) => Some(&self.inner[idx].1),
+                        None => None,
+                    }
+                }
+
+                #[inline]
+                pub const fn get_key_value (&self, key: $t) -> Option<(&'static $t, &'static V)> {
+                    return match self.get_index_of(key) {
+                        Some(idx) => self.get_index(idx),
+                        None => None,
+                    }
+                }
+
+                #[inline]
+                pub const fn contains_key (&self, key: $t) -> bool {
+                    return self.get_index_of(key).is_some()
+                }
+            }
+        )+
+    };
+}
+
+impl_const! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char => |a, b| {
+        if a < b { Ordering::Less } else if a > b { Ordering::Greater } else { Ordering::Equal }
+    }
+}
+
+impl_const! {
+    &'static str => |a, b| {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        let mut i = 0;
+        while i < a.len() && i < b.len() {
+            if a[i] != b[i] {
+                return if a[i] < b[i] { Ordering::Less } else { Ordering::Greater }
+            }
+            i += 1;
+        }
+        if a.len() < b.len() { Ordering::Less } else if a.len() > b.len() { Ordering::Greater } else { Ordering::Equal }
+    }
+}
+
+impl<K: 'static, V: 'static> IntoIterator for StaticBinaryMap<K, V> {
+    type Item = (&'static K, &'static V);
+    type IntoIter = Iter<'static, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: 'static, V: 'static> IntoIterator for &StaticBinaryMap<K, V> {
+    type Item = (&'static K, &'static V);
+    type IntoIter = Iter<'static, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: 'static + Debug, V: 'static + Debug> Debug for StaticBinaryMap<K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Declares `static` or `const` [`StaticBinaryMap`]s from literal entries, whose keys are checked at compile time
+/// to be sorted and unique.
+///
+/// ```
+/// vector_mapp::static_binary_map! {
+///     static OPCODES: u8 => &str = { 0x00 => "nop", 0x01 => "load", 0x02 => "store" };
+///     pub const UNITS: &str => f64 = { "cm" => 0.01, "km" => 1000.0, "m" => 1.0 };
+/// }
+///
+/// assert_eq!(OPCODES.get(0x01), Some(&"load"));
+/// const KM: Option<&f64> = UNITS.get("km");
+/// assert_eq!(KM, Some(&1000.0));
+/// ```
+///
+/// Keys out of order fail to compile:
+///
+/// ```compile_fail,E0080
+/// vector_mapp::static_binary_map! {
+///     static OPCODES: u8 => &str = { 0x01 => "load", 0x00 => "nop" };
+/// }
+/// ```
+///
+/// And so do duplicate keys:
+///
+/// ```compile_fail,E0080
+/// vector_mapp::static_binary_map! {
+///     const UNITS: &str => f64 = { "cm" => 0.01, "m" => 1.0, "m" => 100.0 };
+/// }
+///
+/// assert_eq!(UNITS.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! static_binary_map {
+    ($($(#[$attr:meta])* $vis:vis $kind:ident $name:ident: $k:ty => $v:ty = { $($key:expr => $value:expr),* $(,)? };)+) => {
+        $(
+            $(#[$attr])*
+            $vis $kind $name: $crate::slice::StaticBinaryMap<$k, $v> = $crate::slice::StaticBinaryMap::<$k, $v>::new(&[$(($key, $value)),*]);
+        )+
+    };
+}
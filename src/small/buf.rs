@@ -0,0 +1,378 @@
+use crate::array::buf::{self, ArrayVec};
+use crate::heap::{collections::TryReserveError, vec::Vec};
+use crate::storage::{HeapStorage, MapStorage};
+use core::{hash::Hash, iter::FusedIterator, ops::RangeBounds};
+
+/// A vector that stores up to `N` entries inline, and moves them to the heap storage `H` once it grows past that.
+///
+/// It backs the maps of the [`small`](crate::small) module, but works as the [`HeapStorage`] of any
+/// [`VecMap`](crate::vec::VecMap) or [`BinaryMap`](crate::binary::BinaryMap).
+pub struct SmallVec<T, const N: usize, H = Vec<T>> {
+    inline: ArrayVec<T, N>,
+    // Empty while the entries live inline, so that `H` only has to be a storage of entries
+    heap: H,
+    // Whether the entries live in `heap`. Kept separately from its capacity, which is meaningless for ZSTs
+    spilled: bool,
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self { inline: ArrayVec::new(), heap: Vec::new(), spilled: false }
+    }
+}
+
+#[docfg::docfg(feature = "allocator_api")]
+impl<T, const N: usize, A: crate::heap::alloc::Allocator> SmallVec<T, N, Vec<T, A>> {
+    #[inline]
+    pub const fn new_in (alloc: A) -> Self {
+        return Self { inline: ArrayVec::new(), heap: Vec::new_in(alloc), spilled: false }
+    }
+}
+
+impl<T, const N: usize, H> SmallVec<T, N, H> {
+    #[inline]
+    pub fn is_inline (&self) -> bool {
+        return !self.spilled
+    }
+}
+
+impl<K, V, const N: usize, H: HeapStorage<K, V>> SmallVec<(K, V), N, H> {
+    /// Moves the entries to the heap, with room for at least `additional` more.
+    #[inline]
+    fn spill (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        debug_assert!(!self.spilled);
+        self.heap.try_reserve(self.inline.len() + additional)?;
+        for entry in self.inline.drain(..) {
+            self.heap.insert_growing(self.heap.len(), entry);
+        }
+        self.spilled = true;
+        return Ok(())
+    }
+
+    /// Moves the entries back inline. They must fit in it.
+    #[inline]
+    fn unspill (&mut self) {
+        debug_assert!(self.spilled && self.heap.len() <= N);
+        for entry in self.heap.drain(..) {
+            // the length was checked against `N` by the caller, so every entry fits inline
+            let result = self.inline.push(entry);
+            debug_assert!(result.is_ok());
+        }
+        self.spilled = false;
+    }
+}
+
+/// # Safety
+/// Every method forwards to the buffer that currently holds the entries, either the inline one or `H`, which both
+/// uphold the invariants. The entries only move between them through `reserve`, `insert` and `shrink_to`, which
+/// mutate the storage, and a failed `insert` only fails before spilling.
+unsafe impl<K, V, const N: usize, H: HeapStorage<K, V>> MapStorage<K, V> for SmallVec<(K, V), N, H> {
+    const FIXED_CAPACITY: bool = false;
+
+    #[inline]
+    fn as_slice (&self) -> &[(K, V)] {
+        return match self.spilled {
+            true => self.heap.as_slice(),
+            false => self.inline.as_slice(),
+        }
+    }
+
+    #[inline]
+    fn as_mut_slice (&mut self) -> &mut [(K, V)] {
+        return match self.spilled {
+            true => self.heap.as_mut_slice(),
+            false => self.inline.as_mut_slice(),
+        }
+    }
+
+    #[inline]
+    fn as_mut_ptr (&mut self) -> *mut (K, V) {
+        return match self.spilled {
+            true => self.heap.as_mut_ptr(),
+            false => self.inline.as_mut_ptr(),
+        }
+    }
+
+    #[inline]
+    unsafe fn set_len (&mut self, len: usize) {
+        match self.spilled {
+            true => self.heap.set_len(len),
+            false => self.inline.set_len(len),
+        }
+    }
+
+    /// Only fails if the entries have to be spilled, and the heap can't make room for them.
+    #[inline]
+    fn insert (&mut self, idx: usize, entry: (K, V)) -> Result<(), (K, V)> {
+        if !self.spilled && self.inline.is_full() && self.spill(1).is_err() {
+            return Err(entry)
+        }
+
+        return match self.spilled {
+            true => MapStorage::insert(&mut self.heap, idx, entry),
+            false => self.inline.insert(idx, entry),
+        }
+    }
+
+    #[inline]
+    fn remove (&mut self, idx: usize) -> (K, V) {
+        return match self.spilled {
+            true => MapStorage::remove(&mut self.heap, idx),
+            false => self.inline.remove(idx),
+        }
+    }
+
+    #[inline]
+    fn truncate (&mut self, len: usize) {
+        match self.spilled {
+            true => self.heap.truncate(len),
+            false => self.inline.truncate(len),
+        }
+    }
+
+    #[inline]
+    fn swap_remove (&mut self, idx: usize) -> (K, V) {
+        return match self.spilled {
+            true => self.heap.swap_remove(idx),
+            false => self.inline.swap_remove(idx),
+        }
+    }
+
+    /// Removes every entry, moving the vector back inline while keeping the heap buffer around.
+    #[inline]
+    fn clear (&mut self) {
+        self.inline.clear();
+        self.heap.clear();
+        self.spilled = false;
+    }
+
+    #[inline]
+    fn reserve_hint (&mut self, additional: usize) {
+        let _ = self.try_reserve(additional);
+    }
+}
+
+impl<K, V, const N: usize, H: HeapStorage<K, V>> HeapStorage<K, V> for SmallVec<(K, V), N, H> {
+    type Alloc = H::Alloc;
+    type Boxed = H::Boxed;
+    type Drain<'a> = Drain<'a, (K, V), N, H::Drain<'a>> where Self: 'a;
+
+    /// Starts out on the heap if the capacity doesn't fit inline.
+    #[inline]
+    fn with_capacity_in (capacity: usize, alloc: Self::Alloc) -> Self {
+        let spilled = capacity > N;
+        return Self { inline: ArrayVec::new(), heap: H::with_capacity_in(if spilled { capacity } else { 0 }, alloc), spilled }
+    }
+
+    #[inline]
+    fn allocator (&self) -> &Self::Alloc {
+        return self.heap.allocator()
+    }
+
+    /// The entries stay on the heap, even if they'd fit inline.
+    #[inline]
+    fn from_boxed (boxed: Self::Boxed) -> Self {
+        return Self { inline: ArrayVec::new(), heap: H::from_boxed(boxed), spilled: true }
+    }
+
+    #[inline]
+    fn into_boxed (mut self) -> Self::Boxed {
+        if !self.spilled {
+            self.heap.reserve_exact(self.inline.len());
+            for entry in self.inline.drain(..) {
+                self.heap.insert_growing(self.heap.len(), entry);
+            }
+        }
+        return self.heap.into_boxed()
+    }
+
+    #[inline]
+    fn capacity (&self) -> usize {
+        return match self.spilled {
+            true => self.heap.capacity(),
+            false => N,
+        }
+    }
+
+    #[inline]
+    fn reserve (&mut self, additional: usize) {
+        if self.spilled {
+            self.heap.reserve(additional)
+        } else if self.inline.len().saturating_add(additional) > N {
+            self.heap.reserve(self.inline.len() + additional);
+            let result = self.spill(additional);
+            // the heap was just grown to fit every entry, so spilling them can't fail
+            debug_assert!(result.is_ok());
+        }
+    }
+
+    #[inline]
+    fn reserve_exact (&mut self, additional: usize) {
+        match self.spilled {
+            true => self.heap.reserve_exact(additional),
+            false => self.reserve(additional),
+        }
+    }
+
+    #[inline]
+    fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        return match self.spilled {
+            true => self.heap.try_reserve(additional),
+            false if self.inline.len().saturating_add(additional) > N => self.spill(additional),
+            false => Ok(())
+        }
+    }
+
+    #[inline]
+    fn try_reserve_exact (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        return match self.spilled {
+            true => self.heap.try_reserve_exact(additional),
+            false => self.try_reserve(additional),
+        }
+    }
+
+    /// Moves the entries back inline if `min_capacity` fits in it.
+    #[inline]
+    fn shrink_to (&mut self, min_capacity: usize) {
+        if self.spilled && usize::max(self.heap.len(), min_capacity) <= N {
+            self.unspill();
+        }
+        self.heap.shrink_to(min_capacity)
+    }
+
+    /// Moves the entries back inline if they fit in it.
+    #[inline]
+    fn shrink_to_fit (&mut self) {
+        if self.spilled && self.heap.len() <= N {
+            self.unspill();
+        }
+        self.heap.shrink_to_fit()
+    }
+
+    #[inline]
+    fn insert_growing (&mut self, idx: usize, entry: (K, V)) {
+        if !self.spilled && self.inline.is_full() {
+            self.reserve(1);
+        }
+
+        match self.spilled {
+            true => self.heap.insert_growing(idx, entry),
+            false => {
+                // a full inline buffer was spilled by `reserve` above, so there's always room left in it
+                let result = self.inline.insert(idx, entry);
+                debug_assert!(result.is_ok());
+            }
+        }
+    }
+
+    #[inline]
+    fn drain<R: RangeBounds<usize>> (&mut self, range: R) -> Self::Drain<'_> {
+        return match self.spilled {
+            true => Drain::Heap(self.heap.drain(range)),
+            false => Drain::Inline(self.inline.drain(range)),
+        }
+    }
+}
+
+impl<T, const N: usize, H: Default> Default for SmallVec<T, N, H> {
+    #[inline]
+    fn default() -> Self {
+        Self { inline: ArrayVec::new(), heap: H::default(), spilled: false }
+    }
+}
+
+impl<T: Clone, const N: usize, H: Clone> Clone for SmallVec<T, N, H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { inline: self.inline.clone(), heap: self.heap.clone(), spilled: self.spilled }
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug, const N: usize, H: HeapStorage<K, V>> core::fmt::Debug for SmallVec<(K, V), N, H> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, const N: usize, H: HeapStorage<K, V>> PartialEq for SmallVec<(K, V), N, H> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize, H: HeapStorage<K, V>> Eq for SmallVec<(K, V), N, H> {}
+
+impl<K: Hash, V: Hash, const N: usize, H: HeapStorage<K, V>> Hash for SmallVec<(K, V), N, H> {
+    #[inline]
+    fn hash<S: core::hash::Hasher>(&self, state: &mut S) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<K, V, const N: usize, H: HeapStorage<K, V>> IntoIterator for SmallVec<(K, V), N, H> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<(K, V), N, H::IntoIter>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return match self.spilled {
+            true => IntoIter::Heap(self.heap.into_iter()),
+            false => IntoIter::Inline(self.inline.into_iter()),
+        }
+    }
+}
+
+/// An owning iterator over the entries of a [`SmallVec`], from whichever buffer holds them.
+pub enum IntoIter<T, const N: usize, I> {
+    Inline(buf::IntoIter<T, N>),
+    Heap(I),
+}
+
+/// A draining iterator over the entries of a [`SmallVec`], from whichever buffer holds them.
+pub enum Drain<'a, T, const N: usize, I> {
+    Inline(buf::Drain<'a, T, N>),
+    Heap(I),
+}
+
+macro_rules! impl_iter {
+    (impl[$($g:tt)*] $name:ty) => {
+        impl<$($g)* T, const N: usize, I: Iterator<Item = T>> Iterator for $name {
+            type Item = T;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::Inline(iter) => iter.next(),
+                    Self::Heap(iter) => iter.next(),
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    Self::Inline(iter) => iter.size_hint(),
+                    Self::Heap(iter) => iter.size_hint(),
+                }
+            }
+        }
+
+        impl<$($g)* T, const N: usize, I: DoubleEndedIterator<Item = T>> DoubleEndedIterator for $name {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::Inline(iter) => iter.next_back(),
+                    Self::Heap(iter) => iter.next_back(),
+                }
+            }
+        }
+
+        impl<$($g)* T, const N: usize, I: ExactSizeIterator<Item = T>> ExactSizeIterator for $name {}
+        impl<$($g)* T, const N: usize, I: FusedIterator<Item = T>> FusedIterator for $name {}
+    };
+}
+
+impl_iter!(impl['a,] Drain<'a, T, N, I>);
+impl_iter!(impl[] IntoIter<T, N, I>);
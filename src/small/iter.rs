@@ -0,0 +1,15 @@
+use super::buf;
+use crate::storage::HeapStorage;
+
+pub type Iter<'a, K, V> = crate::vec::Iter<'a, K, V>;
+pub type IterMut<'a, K, V> = crate::vec::IterMut<'a, K, V>;
+pub type Keys<'a, K, V> = crate::vec::Keys<'a, K, V>;
+pub type Values<'a, K, V> = crate::vec::Values<'a, K, V>;
+pub type ValuesMut<'a, K, V> = crate::vec::ValuesMut<'a, K, V>;
+
+#[repr(transparent)]
+pub struct IntoIter<K, V, const N: usize, H: HeapStorage<K, V> = crate::heap::vec::Vec<(K, V)>> (pub(super) buf::IntoIter<(K, V), N, H::IntoIter>);
+
+forward_iter! {
+    impl[K, V, const N: usize, H: HeapStorage<K, V>] IntoIter<K, V, N, H> => (K, V);
+}
@@ -0,0 +1,9 @@
+//! Maps that store a few entries inline, and only allocate once they outgrow them.
+//!
+//! They're a [`VecMap`](crate::vec::VecMap) and a [`BinaryMap`](crate::binary::BinaryMap) backed by a [`SmallVec`],
+//! and dereference to them for everything besides their construction. The heap storage the entries spill into
+//! is a `Vec` by default.
+
+flat_mod! { iter, vec, binary }
+pub(crate) mod buf;
+pub use buf::SmallVec;
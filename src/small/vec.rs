@@ -0,0 +1,170 @@
+use super::{buf::SmallVec, *};
+use crate::{heap::vec::Vec, storage::HeapStorage, vec::{IntoKeys, IntoValues, VecMap}};
+use docfg::docfg;
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use core::{fmt::Debug, ops::{Deref, DerefMut}};
+
+/// A [`VecMap`] that stores up to `N` entries inline, and moves them to the heap once it grows past that.
+///
+/// It's a [`VecMap`] backed by a [`SmallVec`], which it dereferences to for everything besides its construction
+/// and the methods that consume it.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct SmallVecMap<K, V, const N: usize, H = Vec<(K, V)>> {
+    inner: VecMap<K, V, SmallVec<(K, V), N, H>>,
+}
+
+impl<K, V, const N: usize> SmallVecMap<K, V, N> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self { inner: unsafe { VecMap::from_vec_unchecked(SmallVec::new()) } }
+    }
+
+    #[inline]
+    pub fn with_capacity (capacity: usize) -> Self {
+        let mut result = Self::new();
+        result.reserve(capacity);
+        return result
+    }
+}
+
+#[docfg(feature = "allocator_api")]
+impl<K, V, const N: usize, A: Allocator> SmallVecMap<K, V, N, Vec<(K, V), A>> {
+    #[inline]
+    pub const fn new_in (alloc: A) -> Self {
+        return Self { inner: unsafe { VecMap::from_vec_unchecked(SmallVec::new_in(alloc)) } }
+    }
+
+    #[inline]
+    pub fn with_capacity_in (capacity: usize, alloc: A) -> Self {
+        return Self { inner: unsafe { VecMap::from_vec_unchecked(HeapStorage::with_capacity_in(capacity, alloc)) } }
+    }
+}
+
+impl<K, V, const N: usize, H: HeapStorage<K, V>> SmallVecMap<K, V, N, H> {
+    /// Returns `true` if the entries are stored inline, rather than on the heap.
+    #[inline]
+    pub fn is_inline (&self) -> bool {
+        return self.inner.storage().is_inline()
+    }
+
+    #[inline]
+    pub fn into_inner (self) -> VecMap<K, V, SmallVec<(K, V), N, H>> {
+        return self.inner
+    }
+
+    #[inline]
+    pub fn into_keys (self) -> IntoKeys<K, V, SmallVec<(K, V), N, H>> {
+        return self.inner.into_keys()
+    }
+
+    #[inline]
+    pub fn into_values (self) -> IntoValues<K, V, SmallVec<(K, V), N, H>> {
+        return self.inner.into_values()
+    }
+
+    /// Splits the map in two, with the entries that match the predicate in the first one.
+    #[inline]
+    pub fn partition<F: FnMut(&K, &mut V) -> bool> (self, pred: F) -> (Self, Self) where H::Alloc: Clone {
+        let (matching, rest) = self.inner.partition(pred);
+        return (Self { inner: matching }, Self { inner: rest })
+    }
+}
+
+impl<K, V, const N: usize, H> Deref for SmallVecMap<K, V, N, H> {
+    type Target = VecMap<K, V, SmallVec<(K, V), N, H>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K, V, const N: usize, H> DerefMut for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<K, V, const N: usize, H> From<VecMap<K, V, SmallVec<(K, V), N, H>>> for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn from(inner: VecMap<K, V, SmallVec<(K, V), N, H>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<K, V, const N: usize, H: HeapStorage<K, V>> IntoIterator for SmallVecMap<K, V, N, H> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N, H>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.inner.into_iter())
+    }
+}
+
+impl<'a, K, V, const N: usize, H: HeapStorage<K, V>> IntoIterator for &'a SmallVecMap<K, V, N, H> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize, H: HeapStorage<K, V>> IntoIterator for &'a mut SmallVecMap<K, V, N, H> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+impl<K: Eq, V, const N: usize, H: HeapStorage<K, V>> Extend<(K, V)> for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<K: Eq, V, const N: usize, H: HeapStorage<K, V>> FromIterator<(K, V)> for SmallVecMap<K, V, N, H> where H::Alloc: Default {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self { inner: VecMap::from_iter(iter) }
+    }
+}
+
+impl<K, V, const N: usize, H: HeapStorage<K, V> + Default> Default for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn default() -> Self {
+        Self { inner: VecMap::default() }
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize, H: HeapStorage<K, V>> Debug for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, const N: usize, H: HeapStorage<K, V>> PartialEq for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize, H: HeapStorage<K, V>> Eq for SmallVecMap<K, V, N, H> {}
+
+impl<K: core::hash::Hash, V: core::hash::Hash, const N: usize, H: HeapStorage<K, V>> core::hash::Hash for SmallVecMap<K, V, N, H> {
+    #[inline]
+    fn hash<S: core::hash::Hasher>(&self, state: &mut S) {
+        self.inner.hash(state)
+    }
+}
@@ -0,0 +1,536 @@
+use super::*;
+use crate::vec::{many, Compact, Ordered};
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use core::{borrow::Borrow, fmt::Debug, marker::PhantomData, ops::{Index, IndexMut}};
+use docfg::docfg;
+
+/// A [`BinaryMap`](crate::binary::BinaryMap) that stores its keys and values in two separate buffers,
+/// so that binary searches only touch the keys.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SoaBinaryMap<K, V, S: SoaBuffer<K> = Vec<K>> {
+    keys: S,
+    values: S::Rebind<V>,
+}
+
+impl<K, V> SoaBinaryMap<K, V> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self { keys: Vec::new(), values: Vec::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            keys: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+#[docfg(feature = "allocator_api")]
+impl<K, V, A: Allocator + Clone> SoaBinaryMap<K, V, Vec<K, A>> {
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        Self::with_capacity_in(0, alloc)
+    }
+
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let (keys, values) = buffers_in(capacity, alloc);
+        Self { keys, values }
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>> SoaBinaryMap<K, V, S> {
+    /// # Safety
+    /// Both buffers must have the same length, and the keys must be sorted and unique.
+    #[inline]
+    pub unsafe fn from_vecs_unchecked(keys: S, values: S::Rebind<V>) -> Self {
+        debug_assert_eq!(keys.len(), values.len());
+        Self { keys, values }
+    }
+
+    #[inline]
+    pub fn into_vecs(self) -> (S, S::Rebind<V>) {
+        (self.keys, self.values)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        usize::min(self.keys.capacity(), self.values.capacity())
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.keys.truncate(0);
+        self.values.truncate(0);
+    }
+
+    #[inline]
+    pub fn keys_slice(&self) -> &[K] {
+        &self.keys
+    }
+
+    #[inline]
+    pub fn values_slice(&self) -> &[V] {
+        &self.values
+    }
+
+    #[inline]
+    pub fn values_mut_slice(&mut self) -> &mut [V] {
+        &mut self.values
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop)
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.keys.reserve(additional);
+        self.values.reserve(additional);
+    }
+
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.keys.reserve_exact(additional);
+        self.values.reserve_exact(additional);
+    }
+
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.keys.try_reserve(additional)?;
+        self.values.try_reserve(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.keys.try_reserve_exact(additional)?;
+        self.values.try_reserve_exact(additional)
+    }
+
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.keys.shrink_to(min_capacity);
+        self.values.shrink_to(min_capacity);
+    }
+
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+
+    #[inline]
+    pub fn get_index_of<Q>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.keys.binary_search_by(|x| x.borrow().cmp(k)).ok()
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.get_index_of(k).is_some()
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let idx = self.get_index_of(k)?;
+        unsafe { Some(self.values.get_unchecked(idx)) }
+    }
+
+    #[inline]
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let idx = self.get_index_of(k)?;
+        unsafe { Some(self.values.get_unchecked_mut(idx)) }
+    }
+
+    #[inline]
+    pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let idx = self.get_index_of(k)?;
+        self.get_index(idx)
+    }
+
+    #[inline]
+    pub fn get_full<Q>(&self, k: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let idx = self.get_index_of(k)?;
+        let (key, value) = self.get_index(idx)?;
+        Some((idx, key, value))
+    }
+
+    #[inline]
+    pub fn get_index(&self, idx: usize) -> Option<(&K, &V)> {
+        Some((self.keys.get(idx)?, self.values.get(idx)?))
+    }
+
+    #[inline]
+    pub fn get_index_mut(&mut self, idx: usize) -> Option<(&K, &mut V)> {
+        Some((self.keys.get(idx)?, self.values.get_mut(idx)?))
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.remove_entry(k).map(|x| x.1)
+    }
+
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let idx = self.get_index_of(k)?;
+        self.remove_index(idx)
+    }
+
+    #[inline]
+    pub fn remove_index(&mut self, idx: usize) -> Option<(K, V)> {
+        if idx >= self.keys.len() {
+            return None;
+        }
+        Some((self.keys.remove(idx), self.values.remove(idx)))
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        Some((self.keys.pop()?, self.values.pop()?))
+    }
+
+    /// Returns references to the values of all the keys, or `None` if any of them is missing.
+    #[inline]
+    pub fn get_many<Q, const N: usize>(&self, keys: [&Q; N]) -> Option<[&V; N]>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        Some(indices.map(|i| &self.values[i]))
+    }
+
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing
+    /// or if two of them refer to the same entry.
+    #[inline]
+    pub fn get_many_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> Option<[&mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        if !many::is_disjoint(&indices) {
+            return None;
+        }
+        let values = self.values.as_mut_ptr();
+        Some(indices.map(|i| unsafe { &mut *values.add(i) }))
+    }
+
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing.
+    ///
+    /// # Safety
+    /// No two keys may refer to the same entry.
+    #[inline]
+    pub unsafe fn get_many_unchecked_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> Option<[&mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        debug_assert!(many::is_disjoint(&indices));
+        let values = self.values.as_mut_ptr();
+        Some(indices.map(|i| unsafe { &mut *values.add(i) }))
+    }
+
+    /// Returns the entry with the smallest key.
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Returns the entry with the largest key.
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.get_index(self.keys.len().checked_sub(1)?)
+    }
+
+    /// Removes and returns the entry with the smallest key.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.remove_index(0)
+    }
+
+    /// Removes and returns the entry with the largest key.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.pop()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.keys.iter().zip(self.values.iter()))
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut(self.keys.iter().zip(self.values.iter_mut()))
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K> {
+        Keys(self.keys.iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, V> {
+        Values(self.values.iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, V> {
+        ValuesMut(self.values.iter_mut())
+    }
+
+    #[inline]
+    pub fn into_keys(self) -> IntoKeys<K, S> {
+        IntoKeys(self.keys.into_iter(), PhantomData)
+    }
+
+    #[inline]
+    pub fn into_values(self) -> IntoValues<K, V, S> {
+        IntoValues(self.values.into_iter())
+    }
+
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, K, V, S> {
+        Drain(self.keys.drain(..).zip(self.values.drain(..)))
+    }
+
+    /// Removes and yields the entries that match the predicate, in ascending key order.
+    #[inline]
+    pub fn extract_if<F: FnMut(&K, &mut V) -> bool>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self> {
+        ExtractIf::new(self, pred)
+    }
+
+    /// Splits the map in two, with the entries that match the predicate on the first one,
+    /// and the ones that don't on the second. Both maps stay sorted.
+    #[inline]
+    pub fn partition<F: FnMut(&K, &mut V) -> bool>(mut self, mut pred: F) -> (Self, Self)
+    where
+        S::Alloc: Clone,
+    {
+        let (keys, values) = buffers_in(0, self.keys.allocator().clone());
+        let mut rhs = Self { keys, values };
+        for (key, value) in self.extract_if(|k, v| !pred(k, v)) {
+            rhs.keys.push(key);
+            rhs.values.push(value);
+        }
+        (self, rhs)
+    }
+}
+
+impl<K: Ord, V, S: SoaBuffer<K>> SoaBinaryMap<K, V, S> {
+    /// Removals shift the following entries, to keep the keys sorted.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S, Ordered> {
+        match self.keys.binary_search(&key) {
+            Ok(idx) => Entry::Occupied(OccupiedEntry { keys: &mut self.keys, values: &mut self.values, idx, _marker: PhantomData }),
+            Err(idx) => Entry::Vacant(VacantEntry { keys: &mut self.keys, values: &mut self.values, idx, key, _marker: PhantomData }),
+        }
+    }
+
+    /// Inserts the entry if the key isn't already present, returning a reference to the new value.
+    /// Otherwise, the map is left untouched, and an error holding the existing entry and the rejected value is returned.
+    #[inline]
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<OccupiedEntry<'_, K, V, S, Ordered>>> {
+        match self.entry(key) {
+            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
+        }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        return match self.entry(k) {
+            Entry::Occupied(mut x) => Some(x.insert(v)),
+            Entry::Vacant(x) => {
+                x.insert(v);
+                None
+            }
+        };
+    }
+
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entry, handing the entry back alongside it.
+    #[inline]
+    pub fn try_insert_alloc(&mut self, k: K, v: V) -> Result<Option<V>, (TryReserveError, K, V)> {
+        match self.keys.binary_search(&k) {
+            Ok(idx) => Ok(Some(core::mem::replace(&mut self.values[idx], v))),
+            Err(idx) => {
+                if let Err(e) = self.keys.try_reserve(1).and_then(|_| self.values.try_reserve(1)) {
+                    return Err((e, k, v));
+                }
+                self.keys.insert(idx, k);
+                self.values.insert(idx, v);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Same as [`extend`](Extend::extend), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entries. The entries inserted before the failure are kept.
+    #[inline]
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        let iter = iter.into_iter();
+        self.try_reserve(iter.size_hint().0)?;
+        for (k, v) in iter {
+            self.try_insert_alloc(k, v).map_err(|(e, _, _)| e)?;
+        }
+        Ok(())
+    }
+}
+
+/// # Safety
+/// Both buffers are compacted together, and their pointers stay valid up to their capacity.
+unsafe impl<K, V, S: SoaBuffer<K>> Compact<K, V> for SoaBinaryMap<K, V, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        self.keys.set_len(len);
+        self.values.set_len(len);
+    }
+
+    #[inline]
+    unsafe fn entry(&mut self, idx: usize) -> (*mut K, *mut V) {
+        (self.keys.as_mut_ptr().add(idx), self.values.as_mut_ptr().add(idx))
+    }
+
+    #[inline]
+    unsafe fn copy(&mut self, src: usize, dst: usize, count: usize) {
+        let (keys, values) = (self.keys.as_mut_ptr(), self.values.as_mut_ptr());
+        core::ptr::copy(keys.add(src), keys.add(dst), count);
+        core::ptr::copy(values.add(src), values.add(dst), count);
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>> IntoIterator for SoaBinaryMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.keys.into_iter().zip(self.values))
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>> IntoIterator for &'a SoaBinaryMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaBinaryMap::iter(self)
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>> IntoIterator for &'a mut SoaBinaryMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaBinaryMap::iter_mut(self)
+    }
+}
+
+impl<K: Ord, V, S: SoaBuffer<K>> Extend<(K, V)> for SoaBinaryMap<K, V, S> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (k, v) in iter { let _ = self.insert(k, v); }
+    }
+}
+
+impl<K: Ord, V, S: SoaBuffer<K>> FromIterator<(K, V)> for SoaBinaryMap<K, V, S>
+where
+    S::Alloc: Clone + Default,
+{
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut this = Self::default();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>> Default for SoaBinaryMap<K, V, S>
+where
+    S::Alloc: Clone + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        let (keys, values) = buffers_in(0, Default::default());
+        Self { keys, values }
+    }
+}
+
+impl<K: Debug, V: Debug, S: SoaBuffer<K>> Debug for SoaBinaryMap<K, V, S> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<Q: ?Sized + Ord, K: Borrow<Q>, V, S: SoaBuffer<K>> Index<&Q> for SoaBinaryMap<K, V, S> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
+    }
+}
+
+impl<Q: ?Sized + Ord, K: Borrow<Q>, V, S: SoaBuffer<K>> IndexMut<&Q> for SoaBinaryMap<K, V, S> {
+    #[inline]
+    fn index_mut(&mut self, index: &Q) -> &mut Self::Output {
+        self.get_mut(index).expect("index not found")
+    }
+}
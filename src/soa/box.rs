@@ -0,0 +1,240 @@
+use super::*;
+use crate::{search::SearchKey, vec::OrderPolicy};
+use core::{borrow::Borrow, fmt::Debug, hash::{Hash, Hasher}, marker::PhantomData, ops::{Index, IndexMut}};
+
+/// A [`BoxMap`](crate::box::BoxMap) that stores its keys and values in two separate buffers,
+/// so that key lookups don't have to load the values into cache.
+///
+/// Its buffers are the boxed slices of the [`SoaBuffer`] `S`, which it converts to and from.
+pub struct SoaBoxMap<K, V, S: SoaBuffer<K> = Vec<K>> {
+    keys: S::Boxed,
+    values: <S::Rebind<V> as SoaBuffer<V>>::Boxed,
+}
+
+impl<K, V, S: SoaBuffer<K>> SoaBoxMap<K, V, S> {
+    /// # Safety
+    /// Both slices must have the same length, and the keys must be unique.
+    #[inline]
+    pub unsafe fn from_boxes_unchecked (keys: S::Boxed, values: <S::Rebind<V> as SoaBuffer<V>>::Boxed) -> Self {
+        debug_assert_eq!(keys.len(), values.len());
+        return Self { keys, values }
+    }
+
+    #[inline]
+    pub fn into_boxes (self) -> (S::Boxed, <S::Rebind<V> as SoaBuffer<V>>::Boxed) {
+        return (self.keys, self.values)
+    }
+
+    #[inline]
+    pub fn len (&self) -> usize {
+        return self.keys.len();
+    }
+
+    #[inline]
+    pub fn is_empty (&self) -> bool {
+        return self.keys.is_empty();
+    }
+
+    #[inline]
+    pub fn keys_slice (&self) -> &[K] {
+        return &self.keys
+    }
+
+    #[inline]
+    pub fn values_slice (&self) -> &[V] {
+        return &self.values
+    }
+
+    #[inline]
+    pub fn values_mut_slice (&mut self) -> &mut [V] {
+        return &mut self.values
+    }
+
+    #[inline]
+    pub fn get_index (&self, idx: usize) -> Option<(&K, &V)> {
+        return Some((self.keys.get(idx)?, self.values.get(idx)?))
+    }
+
+    #[inline]
+    pub fn get_index_mut (&mut self, idx: usize) -> Option<(&K, &mut V)> {
+        return Some((self.keys.get(idx)?, self.values.get_mut(idx)?))
+    }
+
+    #[inline]
+    pub fn get_index_of<Q: ?Sized + Eq> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return Q::position(&self.keys, key, |k| k)
+    }
+
+    #[inline]
+    pub fn get<Q: ?Sized + Eq> (&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(self.values.get_unchecked(idx)) }
+    }
+
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return self.get_index(idx)
+    }
+
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(self.values.get_unchecked_mut(idx)) }
+    }
+
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Eq> (&self, k: &Q) -> bool where K: Borrow<Q> {
+        self.get_index_of(k).is_some()
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'_, K, V> {
+        return Iter(self.keys.iter().zip(self.values.iter()))
+    }
+
+    #[inline]
+    pub fn iter_mut (&mut self) -> IterMut<'_, K, V> {
+        return IterMut(self.keys.iter().zip(self.values.iter_mut()))
+    }
+
+    #[inline]
+    pub fn keys (&self) -> Keys<'_, K> {
+        return Keys(self.keys.iter())
+    }
+
+    #[inline]
+    pub fn values (&self) -> Values<'_, V> {
+        return Values(self.values.iter())
+    }
+
+    #[inline]
+    pub fn values_mut (&mut self) -> ValuesMut<'_, V> {
+        return ValuesMut(self.values.iter_mut())
+    }
+
+    #[inline]
+    pub fn into_keys (self) -> IntoKeys<K, S> {
+        return IntoKeys(S::from_boxed(self.keys).into_iter(), PhantomData)
+    }
+
+    #[inline]
+    pub fn into_values (self) -> IntoValues<K, V, S> {
+        return IntoValues(<S::Rebind<V>>::from_boxed(self.values).into_iter())
+    }
+
+    /// Returns a new (possibly shorter) map with only the entries that match the predicate.
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool> (self, f: F) -> Self {
+        let mut this = SoaVecMap::<K, V, S>::from(self);
+        this.retain(f);
+        return this.into()
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>> IntoIterator for SoaBoxMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaVecMap::<K, V, S>::from(self).into_iter()
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>> IntoIterator for &'a SoaBoxMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaBoxMap::iter(self)
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>> IntoIterator for &'a mut SoaBoxMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaBoxMap::iter_mut(self)
+    }
+}
+
+impl<K: Eq, V, S: SoaBuffer<K>> FromIterator<(K, V)> for SoaBoxMap<K, V, S> where S::Alloc: Clone + Default {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
+        SoaVecMap::<K, V, S>::from_iter(iter).into()
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>> Default for SoaBoxMap<K, V, S> where S::Alloc: Clone + Default {
+    #[inline]
+    fn default () -> Self {
+        return SoaVecMap::<K, V, S>::default().into()
+    }
+}
+
+// The values' buffer is a qualified path, which `derive` doesn't bound
+impl<K, V, S: SoaBuffer<K>> Clone for SoaBoxMap<K, V, S> where S::Boxed: Clone, <S::Rebind<V> as SoaBuffer<V>>::Boxed: Clone {
+    #[inline]
+    fn clone (&self) -> Self {
+        return Self { keys: self.keys.clone(), values: self.values.clone() }
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, S: SoaBuffer<K>> PartialEq for SoaBoxMap<K, V, S> {
+    #[inline]
+    fn eq (&self, other: &Self) -> bool {
+        return self.keys_slice() == other.keys_slice() && self.values_slice() == other.values_slice()
+    }
+}
+
+impl<K: Eq, V: Eq, S: SoaBuffer<K>> Eq for SoaBoxMap<K, V, S> {}
+
+impl<K: Hash, V: Hash, S: SoaBuffer<K>> Hash for SoaBoxMap<K, V, S> {
+    #[inline]
+    fn hash<H: Hasher> (&self, state: &mut H) {
+        self.keys_slice().hash(state);
+        self.values_slice().hash(state);
+    }
+}
+
+impl<K: Debug, V: Debug, S: SoaBuffer<K>> Debug for SoaBoxMap<K, V, S> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>, P: OrderPolicy> From<SoaVecMap<K, V, S, P>> for SoaBoxMap<K, V, S> {
+    #[inline]
+    fn from(value: SoaVecMap<K, V, S, P>) -> Self {
+        let (keys, values) = value.into_vecs();
+        Self { keys: keys.into_boxed(), values: values.into_boxed() }
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>> From<SoaBoxMap<K, V, S>> for SoaVecMap<K, V, S> {
+    #[inline]
+    fn from(value: SoaBoxMap<K, V, S>) -> Self {
+        unsafe { Self::from_vecs_unchecked(S::from_boxed(value.keys), <S::Rebind<V>>::from_boxed(value.values)) }
+    }
+}
+
+impl<Q: ?Sized + Eq, K: Borrow<Q>, V, S: SoaBuffer<K>> Index<&Q> for SoaBoxMap<K, V, S> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
+    }
+}
+
+impl<Q: ?Sized + Eq, K: Borrow<Q>, V, S: SoaBuffer<K>> IndexMut<&Q> for SoaBoxMap<K, V, S> {
+    #[inline]
+    fn index_mut (&mut self, index: &Q) -> &mut Self::Output {
+        self.get_mut(index).expect("index not found")
+    }
+}
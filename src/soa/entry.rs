@@ -0,0 +1,221 @@
+use super::SoaBuffer;
+use core::{fmt::Debug, marker::PhantomData};
+use crate::{heap::{collections::TryReserveError, vec::Vec}, vec::{sealed::Occupied, OrderPolicy, Unordered}};
+
+pub enum Entry<'a, K: 'a, V: 'a, S: SoaBuffer<K> = Vec<K>, P: OrderPolicy = Unordered> {
+    Occupied(OccupiedEntry<'a, K, V, S, P>),
+    Vacant(VacantEntry<'a, K, V, S, P>),
+}
+
+impl<'a, K, V, S: SoaBuffer<K>, P: OrderPolicy> Entry<'a, K, V, S, P> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(entry.get_mut())
+        }
+        self
+    }
+
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V where V: Default {
+        self.or_insert_with(V::default)
+    }
+
+    /// Sets the value of the entry, and returns an [`OccupiedEntry`] pointing to it.
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, S, P> {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                entry
+            },
+            Entry::Vacant(entry) => entry.insert_entry(value),
+        }
+    }
+}
+
+pub struct OccupiedEntry<'a, K: 'a, V: 'a, S: SoaBuffer<K> = Vec<K>, P: OrderPolicy = Unordered> {
+    pub(super) keys: &'a mut S,
+    pub(super) values: &'a mut S::Rebind<V>,
+    pub(super) idx: usize,
+    pub(super) _marker: PhantomData<P>,
+}
+
+impl<'a, K, V, S: SoaBuffer<K>, P: OrderPolicy> OccupiedEntry<'a, K, V, S, P> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        unsafe { self.keys.get_unchecked(self.idx) }
+    }
+
+    #[inline]
+    pub fn get(&self) -> &V {
+        unsafe { self.values.get_unchecked(self.idx) }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { self.values.get_unchecked_mut(self.idx) }
+    }
+
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { self.values.get_unchecked_mut(self.idx) }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        match P::PRESERVE_ORDER {
+            true => self.shift_remove_entry(),
+            false => self.swap_remove_entry()
+        }
+    }
+
+    #[inline]
+    pub fn swap_remove(self) -> V {
+        self.swap_remove_entry().1
+    }
+
+    #[inline]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        (self.keys.swap_remove(self.idx), self.values.swap_remove(self.idx))
+    }
+
+    #[inline]
+    pub fn shift_remove(self) -> V {
+        self.shift_remove_entry().1
+    }
+
+    #[inline]
+    pub fn shift_remove_entry(self) -> (K, V) {
+        (self.keys.remove(self.idx), self.values.remove(self.idx))
+    }
+}
+
+pub struct VacantEntry<'a, K: 'a, V: 'a, S: SoaBuffer<K> = Vec<K>, P: OrderPolicy = Unordered> {
+    pub(super) keys: &'a mut S,
+    pub(super) values: &'a mut S::Rebind<V>,
+    pub(super) idx: usize,
+    pub(super) key: K,
+    pub(super) _marker: PhantomData<P>,
+}
+
+impl<'a, K, V, S: SoaBuffer<K>, P: OrderPolicy> VacantEntry<'a, K, V, S, P> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.insert_entry(value).into_mut()
+    }
+
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the entry, handing the value back alongside it.
+    #[inline]
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, (TryReserveError, V)> {
+        if let Err(e) = self.keys.try_reserve(1).and_then(|_| self.values.try_reserve(1)) {
+            return Err((e, value));
+        }
+        Ok(self.insert(value))
+    }
+
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, S, P> {
+        // Reserve both buffers upfront, so that their lengths can't get out of sync
+        self.keys.reserve(1);
+        self.values.reserve(1);
+        self.keys.insert(self.idx, self.key);
+        self.values.insert(self.idx, value);
+        OccupiedEntry { keys: self.keys, values: self.values, idx: self.idx, _marker: PhantomData }
+    }
+}
+
+impl<K: Debug, V: Debug, S: SoaBuffer<K>, P: OrderPolicy> Debug for Entry<'_, K, V, S, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Entry::Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Entry::Vacant(entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, S: SoaBuffer<K>, P: OrderPolicy> Debug for OccupiedEntry<'_, K, V, S, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+impl<K: Debug, V, S: SoaBuffer<K>, P: OrderPolicy> Debug for VacantEntry<'_, K, V, S, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>, P: OrderPolicy> Occupied for OccupiedEntry<'a, K, V, S, P> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn key(&self) -> &K {
+        OccupiedEntry::key(self)
+    }
+
+    #[inline]
+    fn get(&self) -> &V {
+        OccupiedEntry::get(self)
+    }
+}
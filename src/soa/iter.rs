@@ -0,0 +1,94 @@
+use super::SoaBuffer;
+use crate::heap::vec::Vec;
+use core::{fmt::Debug, iter::Zip, marker::PhantomData, slice};
+
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Iter<'a, K: 'a, V: 'a> (pub(super) Zip<slice::Iter<'a, K>, slice::Iter<'a, V>>);
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct IterMut<'a, K: 'a, V: 'a> (pub(super) Zip<slice::Iter<'a, K>, slice::IterMut<'a, V>>);
+
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Keys<'a, K: 'a> (pub(super) slice::Iter<'a, K>);
+
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Values<'a, V: 'a> (pub(super) slice::Iter<'a, V>);
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct ValuesMut<'a, V: 'a> (pub(super) slice::IterMut<'a, V>);
+
+#[repr(transparent)]
+pub struct IntoIter<K, V, S: SoaBuffer<K> = Vec<K>> (pub(super) Zip<S::IntoIter, <S::Rebind<V> as IntoIterator>::IntoIter>);
+
+#[repr(transparent)]
+pub struct IntoKeys<K, S: SoaBuffer<K> = Vec<K>> (pub(super) S::IntoIter, pub(super) PhantomData<K>);
+
+#[repr(transparent)]
+pub struct IntoValues<K, V, S: SoaBuffer<K> = Vec<K>> (pub(super) <S::Rebind<V> as IntoIterator>::IntoIter);
+
+#[repr(transparent)]
+pub struct Drain<'a, K: 'a, V: 'a, S: 'a + SoaBuffer<K> = Vec<K>> (pub(super) Zip<S::Drain<'a>, <S::Rebind<V> as SoaBuffer<V>>::Drain<'a>>);
+
+forward_iter! {
+    impl['a, K, V] Iter<'a, K, V> => (&'a K, &'a V);
+    impl['a, K, V] IterMut<'a, K, V> => (&'a K, &'a mut V);
+    impl['a, K] Keys<'a, K> => &'a K;
+    impl['a, V] Values<'a, V> => &'a V;
+    impl['a, V] ValuesMut<'a, V> => &'a mut V;
+    impl[K, V, S: SoaBuffer<K>] IntoIter<K, V, S> => (K, V);
+    impl[K, S: SoaBuffer<K>] IntoKeys<K, S> => K;
+    impl[K, V, S: SoaBuffer<K>] IntoValues<K, V, S> => V;
+    impl['a, K: 'a, V: 'a, S: 'a + SoaBuffer<K>] Drain<'a, K, V, S> => (K, V);
+}
+
+/// Implements `Debug` and `Clone` for the owning iterators, as long as their inner iterators do.
+macro_rules! impl_debug_clone {
+    ($(impl[$($g:tt)*] $name:ident<$($p:tt),*> => $inner:ty;)+) => {
+        $(
+            impl<$($g)*> Debug for $name<$($p),*> where $inner: Debug {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&self.0).finish()
+                }
+            }
+
+            impl<$($g)*> Clone for $name<$($p),*> where $inner: Clone {
+                #[inline]
+                fn clone(&self) -> Self {
+                    Self(self.0.clone())
+                }
+            }
+        )+
+    };
+}
+
+impl_debug_clone! {
+    impl[K, V, S: SoaBuffer<K>] IntoIter<K, V, S> => Zip<S::IntoIter, <S::Rebind<V> as IntoIterator>::IntoIter>;
+    impl[K, V, S: SoaBuffer<K>] IntoValues<K, V, S> => <S::Rebind<V> as IntoIterator>::IntoIter;
+}
+
+impl<K, S: SoaBuffer<K>> Debug for IntoKeys<K, S> where S::IntoIter: Debug {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoKeys").field(&self.0).finish()
+    }
+}
+
+impl<K, S: SoaBuffer<K>> Clone for IntoKeys<K, S> where S::IntoIter: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: 'a + SoaBuffer<K>> Debug for Drain<'a, K, V, S> where Zip<S::Drain<'a>, <S::Rebind<V> as SoaBuffer<V>>::Drain<'a>>: Debug {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Drain").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,229 @@
+//! Struct-of-arrays variants of the maps, which store their keys and values in separate buffers.
+//!
+//! Both buffers of a map are the same kind of [`SoaBuffer`], which defaults to a `Vec`. With the `allocator_api`
+//! feature, a `Vec<K, A>` places both of them in the allocator `A`.
+
+flat_mod! { entry, iter, vec, binary, r#box }
+
+pub use crate::vec::{ExtractIf, OccupiedError};
+
+use core::{cmp::Ordering, iter::FusedIterator, ops::{Deref, DerefMut, RangeBounds}};
+use crate::heap::{collections::TryReserveError, vec::Vec};
+
+/// A growable buffer of the keys or the values of a struct-of-arrays map.
+///
+/// A map is generic over the buffer of its keys, and keeps its values in the same kind of buffer, as given by
+/// [`Rebind`](SoaBuffer::Rebind).
+///
+/// # Safety
+/// The maps rely on the buffer behaving like a `Vec` in their unsafe code, which compacts both buffers in place
+/// through their slices and [`set_len`](SoaBuffer::set_len). An implementation must uphold that:
+/// - The length of the slice it dereferences to only changes through the methods of this trait.
+/// - The slice doesn't move unless the buffer is mutated through a method of this trait, and its pointer stays
+///   valid for reads and writes past its length, up to the capacity of the buffer.
+/// - The methods behave like their `Vec` counterparts, and a failed `try_reserve` leaves the buffer untouched.
+pub unsafe trait SoaBuffer<T>: Deref<Target = [T]> + DerefMut + IntoIterator<Item = T, IntoIter: DoubleEndedIterator + ExactSizeIterator + FusedIterator> {
+    /// The allocator of the buffer, or `()` without the `allocator_api` feature.
+    type Alloc;
+    /// The boxed slice the buffer converts to and from.
+    type Boxed: Deref<Target = [T]> + DerefMut;
+    /// The same kind of buffer, holding another type in the same allocator.
+    type Rebind<U>: SoaBuffer<U, Alloc = Self::Alloc>;
+    type Drain<'a>: DoubleEndedIterator<Item = T> + ExactSizeIterator + FusedIterator where Self: 'a;
+
+    fn with_capacity_in (capacity: usize, alloc: Self::Alloc) -> Self;
+    fn allocator (&self) -> &Self::Alloc;
+    fn from_boxed (boxed: Self::Boxed) -> Self;
+    fn into_boxed (self) -> Self::Boxed;
+
+    fn capacity (&self) -> usize;
+    fn reserve (&mut self, additional: usize);
+    fn reserve_exact (&mut self, additional: usize);
+    fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError>;
+    fn try_reserve_exact (&mut self, additional: usize) -> Result<(), TryReserveError>;
+    fn shrink_to (&mut self, min_capacity: usize);
+    fn shrink_to_fit (&mut self);
+
+    fn push (&mut self, value: T);
+    fn pop (&mut self) -> Option<T>;
+    fn insert (&mut self, idx: usize, value: T);
+    fn remove (&mut self, idx: usize) -> T;
+    fn swap_remove (&mut self, idx: usize) -> T;
+    fn truncate (&mut self, len: usize);
+
+    /// # Safety
+    /// `len` must not exceed the capacity of the buffer, and the first `len` elements must be initialized.
+    unsafe fn set_len (&mut self, len: usize);
+
+    /// Removes the elements in the range, returning them in order.
+    fn drain<R: RangeBounds<usize>> (&mut self, range: R) -> Self::Drain<'_>;
+}
+
+/// Implements [`SoaBuffer`] for `Vec`, given its generics and the items that depend on its allocator.
+macro_rules! impl_vec {
+    (impl[$($g:tt)*] $vec:ty => { $($alloc:tt)* }) => {
+        /// # Safety
+        /// Every method forwards to its `Vec` counterpart.
+        unsafe impl<$($g)*> SoaBuffer<T> for $vec {
+            $($alloc)*
+
+            #[inline]
+            fn into_boxed (self) -> Self::Boxed {
+                return <$vec>::into_boxed_slice(self)
+            }
+
+            #[inline]
+            fn from_boxed (boxed: Self::Boxed) -> Self {
+                return boxed.into_vec()
+            }
+
+            #[inline]
+            fn capacity (&self) -> usize {
+                return <$vec>::capacity(self)
+            }
+
+            #[inline]
+            fn reserve (&mut self, additional: usize) {
+                <$vec>::reserve(self, additional)
+            }
+
+            #[inline]
+            fn reserve_exact (&mut self, additional: usize) {
+                <$vec>::reserve_exact(self, additional)
+            }
+
+            #[inline]
+            fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError> {
+                return <$vec>::try_reserve(self, additional)
+            }
+
+            #[inline]
+            fn try_reserve_exact (&mut self, additional: usize) -> Result<(), TryReserveError> {
+                return <$vec>::try_reserve_exact(self, additional)
+            }
+
+            #[inline]
+            fn shrink_to (&mut self, min_capacity: usize) {
+                <$vec>::shrink_to(self, min_capacity)
+            }
+
+            #[inline]
+            fn shrink_to_fit (&mut self) {
+                <$vec>::shrink_to_fit(self)
+            }
+
+            #[inline]
+            fn push (&mut self, value: T) {
+                <$vec>::push(self, value)
+            }
+
+            #[inline]
+            fn pop (&mut self) -> Option<T> {
+                return <$vec>::pop(self)
+            }
+
+            #[inline]
+            fn insert (&mut self, idx: usize, value: T) {
+                <$vec>::insert(self, idx, value)
+            }
+
+            #[inline]
+            fn remove (&mut self, idx: usize) -> T {
+                return <$vec>::remove(self, idx)
+            }
+
+            #[inline]
+            fn swap_remove (&mut self, idx: usize) -> T {
+                return <$vec>::swap_remove(self, idx)
+            }
+
+            #[inline]
+            fn truncate (&mut self, len: usize) {
+                <$vec>::truncate(self, len)
+            }
+
+            #[inline]
+            unsafe fn set_len (&mut self, len: usize) {
+                <$vec>::set_len(self, len)
+            }
+
+            #[inline]
+            fn drain<R: RangeBounds<usize>> (&mut self, range: R) -> Self::Drain<'_> {
+                return <$vec>::drain(self, range)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "allocator_api")]
+impl_vec! {
+    impl[T, A: crate::heap::alloc::Allocator] Vec<T, A> => {
+        type Alloc = A;
+        type Boxed = crate::heap::boxed::Box<[T], A>;
+        type Rebind<U> = Vec<U, A>;
+        type Drain<'a> = crate::heap::vec::Drain<'a, T, A> where Self: 'a;
+
+        #[inline]
+        fn with_capacity_in (capacity: usize, alloc: A) -> Self {
+            return Self::with_capacity_in(capacity, alloc)
+        }
+
+        #[inline]
+        fn allocator (&self) -> &A {
+            return Vec::allocator(self)
+        }
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl_vec! {
+    impl[T] Vec<T> => {
+        type Alloc = ();
+        type Boxed = crate::heap::boxed::Box<[T]>;
+        type Rebind<U> = Vec<U>;
+        type Drain<'a> = crate::heap::vec::Drain<'a, T> where Self: 'a;
+
+        #[inline]
+        fn with_capacity_in (capacity: usize, _alloc: ()) -> Self {
+            return Self::with_capacity(capacity)
+        }
+
+        #[inline]
+        fn allocator (&self) -> &() {
+            return &()
+        }
+    }
+}
+
+/// Allocates the buffers of a map with room for `capacity` entries, both in `alloc`.
+#[inline]
+pub(crate) fn buffers_in<K, V, S: SoaBuffer<K>> (capacity: usize, alloc: S::Alloc) -> (S, S::Rebind<V>) where S::Alloc: Clone {
+    return (S::with_capacity_in(capacity, alloc.clone()), <S::Rebind<V>>::with_capacity_in(capacity, alloc))
+}
+
+/// Sorts both slices by their entries, as ordered by `cmp`. Since the keys and values can't be sorted together,
+/// the positions are sorted first, and then both slices are permuted in place.
+#[inline]
+pub(crate) fn sort_by<K, V, F: FnMut((&K, &V), (&K, &V)) -> Ordering> (keys: &mut [K], values: &mut [V], stable: bool, mut cmp: F) {
+    debug_assert_eq!(keys.len(), values.len());
+
+    let mut order = (0..keys.len()).collect::<Vec<_>>();
+    let mut cmp = |&i: &usize, &j: &usize| cmp((&keys[i], &values[i]), (&keys[j], &values[j]));
+    match stable {
+        true => order.sort_by(&mut cmp),
+        false => order.sort_unstable_by(&mut cmp)
+    }
+
+    // `order[i]` is the position of the entry that goes to `i`. Follow each cycle, marking the visited positions.
+    for i in 0..order.len() {
+        let mut j = i;
+        while order[j] != i {
+            let next = order[j];
+            keys.swap(j, next);
+            values.swap(j, next);
+            order[j] = j;
+            j = next;
+        }
+        order[j] = j;
+    }
+}
@@ -0,0 +1,598 @@
+use super::*;
+use crate::{search::SearchKey, vec::{many, Compact, OrderPolicy, Unordered}};
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use core::{borrow::Borrow, fmt::Debug, marker::PhantomData, ops::{Index, IndexMut}};
+use docfg::docfg;
+
+/// A [`VecMap`](crate::vec::VecMap) that stores its keys and values in two separate buffers,
+/// so that key lookups don't have to load the values into cache.
+///
+/// Like the [`VecMap`](crate::vec::VecMap), its [`OrderPolicy`] decides whether removals preserve the order of
+/// the remaining entries.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SoaVecMap<K, V, S: SoaBuffer<K> = Vec<K>, P: OrderPolicy = Unordered> {
+    keys: S,
+    values: S::Rebind<V>,
+    _marker: PhantomData<P>,
+}
+
+impl<K, V> SoaVecMap<K, V> {
+    #[inline]
+    pub const fn new () -> Self {
+        return Self::with_policy(Unordered)
+    }
+
+    #[inline]
+    pub fn with_capacity (capacity: usize) -> Self {
+        return Self::with_capacity_and_policy(capacity, Unordered)
+    }
+}
+
+impl<K, V, P: OrderPolicy> SoaVecMap<K, V, Vec<K>, P> {
+    #[inline]
+    pub const fn with_policy (_policy: P) -> Self {
+        return Self { keys: Vec::new(), values: Vec::new(), _marker: PhantomData }
+    }
+
+    #[inline]
+    pub fn with_capacity_and_policy (capacity: usize, _policy: P) -> Self {
+        return Self { keys: Vec::with_capacity(capacity), values: Vec::with_capacity(capacity), _marker: PhantomData }
+    }
+}
+
+#[docfg(feature = "allocator_api")]
+impl<K, V, A: Allocator + Clone> SoaVecMap<K, V, Vec<K, A>> {
+    #[inline]
+    pub fn new_in (alloc: A) -> Self {
+        return Self::with_capacity_in(0, alloc)
+    }
+
+    #[inline]
+    pub fn with_capacity_in (capacity: usize, alloc: A) -> Self {
+        let (keys, values) = buffers_in(capacity, alloc);
+        return Self { keys, values, _marker: PhantomData }
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>, P: OrderPolicy> SoaVecMap<K, V, S, P> {
+    /// # Safety
+    /// Both buffers must have the same length, and the keys must be unique.
+    #[inline]
+    pub unsafe fn from_vecs_unchecked (keys: S, values: S::Rebind<V>) -> Self {
+        debug_assert_eq!(keys.len(), values.len());
+        return Self { keys, values, _marker: PhantomData }
+    }
+
+    #[inline]
+    pub fn into_vecs (self) -> (S, S::Rebind<V>) {
+        return (self.keys, self.values)
+    }
+
+    #[inline]
+    pub fn into_policy<Q: OrderPolicy> (self) -> SoaVecMap<K, V, S, Q> {
+        return SoaVecMap { keys: self.keys, values: self.values, _marker: PhantomData }
+    }
+
+    #[inline]
+    pub fn len (&self) -> usize {
+        return self.keys.len();
+    }
+
+    #[inline]
+    pub fn is_empty (&self) -> bool {
+        return self.keys.is_empty();
+    }
+
+    #[inline]
+    pub fn capacity (&self) -> usize {
+        return usize::min(self.keys.capacity(), self.values.capacity())
+    }
+
+    #[inline]
+    pub fn clear (&mut self) {
+        self.keys.truncate(0);
+        self.values.truncate(0);
+    }
+
+    #[inline]
+    pub fn keys_slice (&self) -> &[K] {
+        return &self.keys
+    }
+
+    #[inline]
+    pub fn values_slice (&self) -> &[V] {
+        return &self.values
+    }
+
+    #[inline]
+    pub fn values_mut_slice (&mut self) -> &mut [V] {
+        return &mut self.values
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool> (&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop)
+    }
+
+    #[inline]
+    pub fn reserve (&mut self, additional: usize) {
+        self.keys.reserve(additional);
+        self.values.reserve(additional);
+    }
+
+    #[inline]
+    pub fn reserve_exact (&mut self, additional: usize) {
+        self.keys.reserve_exact(additional);
+        self.values.reserve_exact(additional);
+    }
+
+    #[inline]
+    pub fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.keys.try_reserve(additional)?;
+        return self.values.try_reserve(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve_exact (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.keys.try_reserve_exact(additional)?;
+        return self.values.try_reserve_exact(additional)
+    }
+
+    #[inline]
+    pub fn shrink_to (&mut self, min_capacity: usize) {
+        self.keys.shrink_to(min_capacity);
+        self.values.shrink_to(min_capacity);
+    }
+
+    #[inline]
+    pub fn shrink_to_fit (&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+
+    #[inline]
+    pub fn get_index_of<Q: ?Sized + Eq> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return Q::position(&self.keys, key, |k| k)
+    }
+
+    #[inline]
+    pub fn get<Q: ?Sized + Eq> (&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(self.values.get_unchecked(idx)) }
+    }
+
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return self.get_index(idx)
+    }
+
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(self.values.get_unchecked_mut(idx)) }
+    }
+
+    #[inline]
+    pub fn get_full<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(usize, &K, &V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        let (k, v) = self.get_index(idx)?;
+        return Some((idx, k, v))
+    }
+
+    #[inline]
+    pub fn get_index (&self, idx: usize) -> Option<(&K, &V)> {
+        return Some((self.keys.get(idx)?, self.values.get(idx)?))
+    }
+
+    #[inline]
+    pub fn get_index_mut (&mut self, idx: usize) -> Option<(&K, &mut V)> {
+        return Some((self.keys.get(idx)?, self.values.get_mut(idx)?))
+    }
+
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Eq> (&self, k: &Q) -> bool where K: Borrow<Q> {
+        self.get_index_of(k).is_some()
+    }
+
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.remove_entry(key).map(|x| x.1)
+    }
+
+    #[inline]
+    pub fn remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        return match P::PRESERVE_ORDER {
+            true => self.shift_remove_entry(key),
+            false => self.swap_remove_entry(key)
+        }
+    }
+
+    #[inline]
+    pub fn swap_remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.swap_remove_entry(key).map(|x| x.1)
+    }
+
+    #[inline]
+    pub fn swap_remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return Some((self.keys.swap_remove(idx), self.values.swap_remove(idx)))
+    }
+
+    #[inline]
+    pub fn shift_remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.shift_remove_entry(key).map(|x| x.1)
+    }
+
+    #[inline]
+    pub fn shift_remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return Some((self.keys.remove(idx), self.values.remove(idx)))
+    }
+
+    /// Returns references to the values of all the keys, or `None` if any of them is missing.
+    #[inline]
+    pub fn get_many<Q: ?Sized + Eq, const N: usize> (&self, keys: [&Q; N]) -> Option<[&V; N]> where K: Borrow<Q> {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        return Some(indices.map(|i| &self.values[i]))
+    }
+
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing
+    /// or if two of them refer to the same entry.
+    #[inline]
+    pub fn get_many_mut<Q: ?Sized + Eq, const N: usize> (&mut self, keys: [&Q; N]) -> Option<[&mut V; N]> where K: Borrow<Q> {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        if !many::is_disjoint(&indices) { return None }
+        let values = self.values.as_mut_ptr();
+        return Some(indices.map(|i| unsafe { &mut *values.add(i) }))
+    }
+
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing.
+    ///
+    /// # Safety
+    /// No two keys may refer to the same entry.
+    #[inline]
+    pub unsafe fn get_many_unchecked_mut<Q: ?Sized + Eq, const N: usize> (&mut self, keys: [&Q; N]) -> Option<[&mut V; N]> where K: Borrow<Q> {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        debug_assert!(many::is_disjoint(&indices));
+        let values = self.values.as_mut_ptr();
+        return Some(indices.map(|i| unsafe { &mut *values.add(i) }))
+    }
+
+    #[inline]
+    pub fn swap_remove_index (&mut self, idx: usize) -> Option<(K, V)> {
+        if idx >= self.keys.len() { return None }
+        return Some((self.keys.swap_remove(idx), self.values.swap_remove(idx)))
+    }
+
+    #[inline]
+    pub fn shift_remove_index (&mut self, idx: usize) -> Option<(K, V)> {
+        if idx >= self.keys.len() { return None }
+        return Some((self.keys.remove(idx), self.values.remove(idx)))
+    }
+
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap_indices (&mut self, a: usize, b: usize) {
+        self.keys.swap(a, b);
+        self.values.swap(a, b);
+    }
+
+    /// Moves the entry at position `from` to position `to`, shifting the entries in between.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` are out of bounds.
+    #[inline]
+    pub fn move_index (&mut self, from: usize, to: usize) {
+        if from < to {
+            self.keys[from..=to].rotate_left(1);
+            self.values[from..=to].rotate_left(1);
+        } else if to < from {
+            self.keys[to..=from].rotate_right(1);
+            self.values[to..=from].rotate_right(1);
+        }
+    }
+
+    /// Returns the index of the first entry whose key matches the predicate.
+    #[inline]
+    pub fn position_by<F: FnMut(&K) -> bool> (&self, f: F) -> Option<usize> {
+        return self.keys.iter().position(f)
+    }
+
+    /// Returns the first entry whose key matches the predicate, along with its index.
+    #[inline]
+    pub fn find_by<F: FnMut(&K) -> bool> (&self, f: F) -> Option<(usize, &K, &V)> {
+        let idx = self.position_by(f)?;
+        let (k, v) = self.get_index(idx)?;
+        return Some((idx, k, v))
+    }
+
+    /// Returns the first entry whose key matches the predicate, along with its index.
+    #[inline]
+    pub fn find_by_mut<F: FnMut(&K) -> bool> (&mut self, f: F) -> Option<(usize, &K, &mut V)> {
+        let idx = self.position_by(f)?;
+        let (k, v) = self.get_index_mut(idx)?;
+        return Some((idx, k, v))
+    }
+
+    #[inline]
+    pub fn sort_keys (&mut self) where K: Ord {
+        self.sort_unstable_by(|(x, _), (y, _)| x.cmp(y))
+    }
+
+    #[inline]
+    pub fn sort_by_values (&mut self) where V: Ord {
+        self.sort_by(|(_, x), (_, y)| x.cmp(y))
+    }
+
+    #[inline]
+    pub fn sort_by<F: FnMut((&K, &V), (&K, &V)) -> Ordering> (&mut self, cmp: F) {
+        sort_by(&mut self.keys, &mut self.values, true, cmp)
+    }
+
+    #[inline]
+    pub fn sort_unstable_by<F: FnMut((&K, &V), (&K, &V)) -> Ordering> (&mut self, cmp: F) {
+        sort_by(&mut self.keys, &mut self.values, false, cmp)
+    }
+
+    #[inline]
+    pub fn sort_unstable_by_key<T: Ord, F: FnMut(&K, &V) -> T> (&mut self, mut f: F) {
+        self.sort_unstable_by(|(k1, v1), (k2, v2)| f(k1, v1).cmp(&f(k2, v2)))
+    }
+
+    #[inline]
+    pub fn entry (&mut self, key: K) -> Entry<'_, K, V, S, P> where K: Eq {
+        if let Some(idx) = K::position(&self.keys, &key, |k| k) {
+            return Entry::Occupied(OccupiedEntry { keys: &mut self.keys, values: &mut self.values, idx, _marker: PhantomData })
+        }
+        return Entry::Vacant(VacantEntry { idx: self.keys.len(), keys: &mut self.keys, values: &mut self.values, key, _marker: PhantomData })
+    }
+
+    #[inline]
+    pub fn insert (&mut self, key: K, value: V) -> Option<V> where K: Eq {
+        return match self.entry(key) {
+            Entry::Occupied(mut x) => Some(x.insert(value)),
+            Entry::Vacant(x) => {
+                x.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Inserts the entry at position `idx`, shifting all the following entries.
+    /// If the key was already present, its value is replaced and the entry is moved to `idx`.
+    /// In both cases, an `idx` of `self.len()` places the entry last.
+    ///
+    /// # Panics
+    /// Panics if `idx > self.len()`, before modifying the map.
+    #[inline]
+    pub fn insert_at (&mut self, idx: usize, key: K, value: V) -> Option<V> where K: Eq {
+        let len = self.keys.len();
+        assert!(idx <= len, "insertion index (is {idx}) should be <= len (is {len})");
+
+        return match self.get_index_of(&key) {
+            Some(prev) => {
+                let value = core::mem::replace(&mut self.values[prev], value);
+                self.move_index(prev, usize::min(idx, len - 1));
+                Some(value)
+            },
+            None => {
+                // Reserve both buffers upfront, so that their lengths can't get out of sync
+                self.reserve(1);
+                self.keys.insert(idx, key);
+                self.values.insert(idx, value);
+                None
+            }
+        }
+    }
+
+    /// Inserts the entry if the key isn't already present, returning a reference to the new value.
+    /// Otherwise, the map is left untouched, and an error holding the existing entry and the rejected value is returned.
+    #[inline]
+    pub fn try_insert (&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<OccupiedEntry<'_, K, V, S, P>>> where K: Eq {
+        return match self.entry(key) {
+            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+            Entry::Vacant(entry) => Ok(entry.insert(value))
+        }
+    }
+
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entry, handing the entry back alongside it.
+    #[inline]
+    pub fn try_insert_alloc (&mut self, key: K, value: V) -> Result<Option<V>, (TryReserveError, K, V)> where K: Eq {
+        if let Some(idx) = self.get_index_of(&key) {
+            return Ok(Some(core::mem::replace(&mut self.values[idx], value)))
+        }
+
+        if let Err(e) = self.keys.try_reserve(1).and_then(|_| self.values.try_reserve(1)) {
+            return Err((e, key, value))
+        }
+        self.keys.push(key);
+        self.values.push(value);
+        return Ok(None)
+    }
+
+    /// Same as [`extend`](Extend::extend), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entries. The entries inserted before the failure are kept.
+    #[inline]
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>> (&mut self, iter: I) -> Result<(), TryReserveError> where K: Eq {
+        let iter = iter.into_iter();
+        self.try_reserve(iter.size_hint().0)?;
+        for (key, value) in iter {
+            self.try_insert_alloc(key, value).map_err(|(e, _, _)| e)?;
+        }
+        return Ok(())
+    }
+
+    #[inline]
+    pub fn iter (&self) -> Iter<'_, K, V> {
+        return Iter(self.keys.iter().zip(self.values.iter()))
+    }
+
+    #[inline]
+    pub fn iter_mut (&mut self) -> IterMut<'_, K, V> {
+        return IterMut(self.keys.iter().zip(self.values.iter_mut()))
+    }
+
+    #[inline]
+    pub fn keys (&self) -> Keys<'_, K> {
+        return Keys(self.keys.iter())
+    }
+
+    #[inline]
+    pub fn values (&self) -> Values<'_, V> {
+        return Values(self.values.iter())
+    }
+
+    #[inline]
+    pub fn values_mut (&mut self) -> ValuesMut<'_, V> {
+        return ValuesMut(self.values.iter_mut())
+    }
+
+    #[inline]
+    pub fn into_keys (self) -> IntoKeys<K, S> {
+        return IntoKeys(self.keys.into_iter(), PhantomData)
+    }
+
+    #[inline]
+    pub fn into_values (self) -> IntoValues<K, V, S> {
+        return IntoValues(self.values.into_iter())
+    }
+
+    #[inline]
+    pub fn drain (&mut self) -> Drain<'_, K, V, S> {
+        return Drain(self.keys.drain(..).zip(self.values.drain(..)))
+    }
+
+    #[inline]
+    pub fn extract_if<F: FnMut(&K, &mut V) -> bool> (&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self> {
+        return ExtractIf::new(self, pred)
+    }
+
+    /// Splits the map in two, with the entries that match the predicate on the first one,
+    /// and the ones that don't on the second. Both maps keep the entries' relative order.
+    #[inline]
+    pub fn partition<F: FnMut(&K, &mut V) -> bool> (mut self, mut pred: F) -> (Self, Self) where S::Alloc: Clone {
+        let (keys, values) = buffers_in(0, self.keys.allocator().clone());
+        let mut rhs = Self { keys, values, _marker: PhantomData };
+        for (key, value) in self.extract_if(|k, v| !pred(k, v)) {
+            rhs.keys.push(key);
+            rhs.values.push(value);
+        }
+        return (self, rhs)
+    }
+}
+
+/// # Safety
+/// Both buffers are compacted together, and their pointers stay valid up to their capacity.
+unsafe impl<K, V, S: SoaBuffer<K>, P: OrderPolicy> Compact<K, V> for SoaVecMap<K, V, S, P> {
+    #[inline]
+    fn len (&self) -> usize {
+        return self.keys.len()
+    }
+
+    #[inline]
+    unsafe fn set_len (&mut self, len: usize) {
+        self.keys.set_len(len);
+        self.values.set_len(len);
+    }
+
+    #[inline]
+    unsafe fn entry (&mut self, idx: usize) -> (*mut K, *mut V) {
+        return (self.keys.as_mut_ptr().add(idx), self.values.as_mut_ptr().add(idx))
+    }
+
+    #[inline]
+    unsafe fn copy (&mut self, src: usize, dst: usize, count: usize) {
+        let (keys, values) = (self.keys.as_mut_ptr(), self.values.as_mut_ptr());
+        core::ptr::copy(keys.add(src), keys.add(dst), count);
+        core::ptr::copy(values.add(src), values.add(dst), count);
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>, P: OrderPolicy> IntoIterator for SoaVecMap<K, V, S, P> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.keys.into_iter().zip(self.values))
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>, P: OrderPolicy> IntoIterator for &'a SoaVecMap<K, V, S, P> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaVecMap::iter(self)
+    }
+}
+
+impl<'a, K, V, S: SoaBuffer<K>, P: OrderPolicy> IntoIterator for &'a mut SoaVecMap<K, V, S, P> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SoaVecMap::iter_mut(self)
+    }
+}
+
+impl<K: Eq, V, S: SoaBuffer<K>, P: OrderPolicy> Extend<(K, V)> for SoaVecMap<K, V, S, P> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            let _ = self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq, V, S: SoaBuffer<K>, P: OrderPolicy> FromIterator<(K, V)> for SoaVecMap<K, V, S, P> where S::Alloc: Clone + Default {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
+        let iter = iter.into_iter();
+        let (keys, values) = buffers_in(match iter.size_hint() {
+            (_, Some(x)) => x,
+            (x, _) => x
+        }, Default::default());
+
+        let mut result = Self { keys, values, _marker: PhantomData };
+        result.extend(iter);
+        return result
+    }
+}
+
+impl<K, V, S: SoaBuffer<K>, P: OrderPolicy> Default for SoaVecMap<K, V, S, P> where S::Alloc: Clone + Default {
+    #[inline]
+    fn default() -> Self {
+        let (keys, values) = buffers_in(0, Default::default());
+        Self { keys, values, _marker: PhantomData }
+    }
+}
+
+impl<K: Debug, V: Debug, S: SoaBuffer<K>, P: OrderPolicy> Debug for SoaVecMap<K, V, S, P> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<Q: ?Sized + Eq, K: Borrow<Q>, V, S: SoaBuffer<K>, P: OrderPolicy> Index<&Q> for SoaVecMap<K, V, S, P> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
+    }
+}
+
+impl<Q: ?Sized + Eq, K: Borrow<Q>, V, S: SoaBuffer<K>, P: OrderPolicy> IndexMut<&Q> for SoaVecMap<K, V, S, P> {
+    #[inline]
+    fn index_mut (&mut self, index: &Q) -> &mut Self::Output {
+        self.get_mut(index).expect("index not found")
+    }
+}
@@ -0,0 +1,400 @@
+//! The buffers the entries of a [`VecMap`](crate::vec::VecMap) or a [`BinaryMap`](crate::binary::BinaryMap) are stored in.
+//!
+//! Both maps are generic over their [`MapStorage`], which defaults to a `Vec`. They can also run on an [`ArrayVec`],
+//! a [`SliceVec`] over a buffer provided by the caller or, with the `heapless` feature, a `heapless::Vec`.
+//! Since those have a fixed capacity, their insertions return the entry back if it doesn't fit. The methods that
+//! grow the map on demand, like `insert` or `reserve`, are only available on a [`HeapStorage`].
+
+flat_mod! { slice_vec }
+
+use crate::array::ArrayVec;
+
+/// A buffer of entries that a [`VecMap`](crate::vec::VecMap) or a [`BinaryMap`](crate::binary::BinaryMap) can be built upon.
+///
+/// The storage only holds the entries. Keeping their keys unique (and sorted) is up to the map.
+///
+/// # Safety
+/// The maps rely on the storage behaving like a `Vec` in their unsafe code, which compacts the buffer in place
+/// through [`as_mut_ptr`](MapStorage::as_mut_ptr) and [`set_len`](MapStorage::set_len), and indexes it unchecked
+/// with positions found in [`as_slice`](MapStorage::as_slice). An implementation must uphold that:
+/// - `as_slice` and `as_mut_slice` both start at `as_mut_ptr`, and their length is always the length of the storage,
+///   which only changes through `insert`, `remove`, `truncate` and `set_len`.
+/// - The pointer returned by `as_mut_ptr` is valid for reads and writes up to the capacity of the storage, and the
+///   buffer doesn't move unless the storage is mutated through a method other than `as_mut_slice`.
+/// - `insert` and `remove` shift the following entries by one position, keeping their order, like their `Vec`
+///   counterparts do, and a failed `insert` leaves the storage untouched.
+pub unsafe trait MapStorage<K, V> {
+    /// Whether the storage has a fixed capacity, so inserting into a full one fails instead of growing it.
+    const FIXED_CAPACITY: bool = true;
+
+    fn as_slice (&self) -> &[(K, V)];
+    fn as_mut_slice (&mut self) -> &mut [(K, V)];
+
+    /// Returns a pointer to the start of the buffer, which stays valid past the length of the storage.
+    fn as_mut_ptr (&mut self) -> *mut (K, V);
+
+    /// # Safety
+    /// `len` must not exceed the capacity of the storage, and the first `len` entries must be initialized.
+    unsafe fn set_len (&mut self, len: usize);
+
+    /// Inserts the entry at `idx`, shifting the following ones to the right, or returns it back if the storage is full.
+    fn insert (&mut self, idx: usize, entry: (K, V)) -> Result<(), (K, V)>;
+
+    /// Removes the entry at `idx`, shifting the following ones to the left.
+    fn remove (&mut self, idx: usize) -> (K, V);
+
+    /// Drops every entry past the first `len`.
+    fn truncate (&mut self, len: usize);
+
+    #[inline]
+    fn len (&self) -> usize {
+        return self.as_slice().len()
+    }
+
+    #[inline]
+    fn is_empty (&self) -> bool {
+        return self.as_slice().is_empty()
+    }
+
+    /// Removes the entry at `idx`, replacing it with the last one.
+    #[inline]
+    fn swap_remove (&mut self, idx: usize) -> (K, V) {
+        let last = self.len() - 1;
+        self.as_mut_slice().swap(idx, last);
+        return self.remove(last)
+    }
+
+    #[inline]
+    fn clear (&mut self) {
+        self.truncate(0)
+    }
+
+    /// Hints that `additional` entries are about to be inserted, so a storage that can grow makes room for them upfront.
+    /// Failing to do so isn't an error, since the insertions grow the storage on their own.
+    #[inline]
+    fn reserve_hint (&mut self, additional: usize) {
+        let _ = additional;
+    }
+}
+
+/// A [`MapStorage`] on the heap, which grows as entries are inserted.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait HeapStorage<K, V>: MapStorage<K, V> + IntoIterator<Item = (K, V), IntoIter: DoubleEndedIterator + ExactSizeIterator + core::iter::FusedIterator> {
+    /// The allocator of the storage, or `()` without the `allocator_api` feature.
+    type Alloc;
+    /// The boxed slice the storage converts to and from.
+    type Boxed;
+    type Drain<'a>: DoubleEndedIterator<Item = (K, V)> + ExactSizeIterator + core::iter::FusedIterator where Self: 'a;
+
+    fn with_capacity_in (capacity: usize, alloc: Self::Alloc) -> Self;
+    fn allocator (&self) -> &Self::Alloc;
+    fn from_boxed (boxed: Self::Boxed) -> Self;
+    fn into_boxed (self) -> Self::Boxed;
+
+    fn capacity (&self) -> usize;
+    fn reserve (&mut self, additional: usize);
+    fn reserve_exact (&mut self, additional: usize);
+    fn try_reserve (&mut self, additional: usize) -> Result<(), crate::heap::collections::TryReserveError>;
+    fn try_reserve_exact (&mut self, additional: usize) -> Result<(), crate::heap::collections::TryReserveError>;
+    fn shrink_to (&mut self, min_capacity: usize);
+    fn shrink_to_fit (&mut self);
+
+    /// Inserts the entry at `idx`, shifting the following ones to the right, and growing the storage if it's full.
+    fn insert_growing (&mut self, idx: usize, entry: (K, V));
+
+    /// Removes the entries in the range, returning them in order.
+    fn drain<R: core::ops::RangeBounds<usize>> (&mut self, range: R) -> Self::Drain<'_>;
+}
+
+/// Implements both storage traits for `Vec`, given its generics and the items that depend on its allocator.
+#[cfg(feature = "alloc")]
+macro_rules! impl_vec {
+    (impl[$($g:tt)*] $vec:ty => { $($alloc:tt)* }) => {
+        /// # Safety
+        /// Every method forwards to its `Vec` counterpart, which the invariants are modeled after.
+        unsafe impl<$($g)*> MapStorage<K, V> for $vec {
+            const FIXED_CAPACITY: bool = false;
+
+            #[inline]
+            fn as_slice (&self) -> &[(K, V)] {
+                return self
+            }
+
+            #[inline]
+            fn as_mut_slice (&mut self) -> &mut [(K, V)] {
+                return self
+            }
+
+            #[inline]
+            fn as_mut_ptr (&mut self) -> *mut (K, V) {
+                return <$vec>::as_mut_ptr(self)
+            }
+
+            #[inline]
+            unsafe fn set_len (&mut self, len: usize) {
+                <$vec>::set_len(self, len)
+            }
+
+            /// Only fails if the vector can't grow.
+            #[inline]
+            fn insert (&mut self, idx: usize, entry: (K, V)) -> Result<(), (K, V)> {
+                if <$vec>::try_reserve(self, 1).is_err() {
+                    return Err(entry)
+                }
+                <$vec>::insert(self, idx, entry);
+                return Ok(())
+            }
+
+            #[inline]
+            fn remove (&mut self, idx: usize) -> (K, V) {
+                return <$vec>::remove(self, idx)
+            }
+
+            #[inline]
+            fn truncate (&mut self, len: usize) {
+                <$vec>::truncate(self, len)
+            }
+
+            #[inline]
+            fn swap_remove (&mut self, idx: usize) -> (K, V) {
+                return <$vec>::swap_remove(self, idx)
+            }
+
+            #[inline]
+            fn reserve_hint (&mut self, additional: usize) {
+                let _ = <$vec>::try_reserve(self, additional);
+            }
+        }
+
+        impl<$($g)*> HeapStorage<K, V> for $vec {
+            $($alloc)*
+
+            #[inline]
+            fn into_boxed (self) -> Self::Boxed {
+                return <$vec>::into_boxed_slice(self)
+            }
+
+            #[inline]
+            fn from_boxed (boxed: Self::Boxed) -> Self {
+                return boxed.into_vec()
+            }
+
+            #[inline]
+            fn capacity (&self) -> usize {
+                return <$vec>::capacity(self)
+            }
+
+            #[inline]
+            fn reserve (&mut self, additional: usize) {
+                <$vec>::reserve(self, additional)
+            }
+
+            #[inline]
+            fn reserve_exact (&mut self, additional: usize) {
+                <$vec>::reserve_exact(self, additional)
+            }
+
+            #[inline]
+            fn try_reserve (&mut self, additional: usize) -> Result<(), crate::heap::collections::TryReserveError> {
+                return <$vec>::try_reserve(self, additional)
+            }
+
+            #[inline]
+            fn try_reserve_exact (&mut self, additional: usize) -> Result<(), crate::heap::collections::TryReserveError> {
+                return <$vec>::try_reserve_exact(self, additional)
+            }
+
+            #[inline]
+            fn shrink_to (&mut self, min_capacity: usize) {
+                <$vec>::shrink_to(self, min_capacity)
+            }
+
+            #[inline]
+            fn shrink_to_fit (&mut self) {
+                <$vec>::shrink_to_fit(self)
+            }
+
+            #[inline]
+            fn insert_growing (&mut self, idx: usize, entry: (K, V)) {
+                <$vec>::insert(self, idx, entry)
+            }
+
+            #[inline]
+            fn drain<R: core::ops::RangeBounds<usize>> (&mut self, range: R) -> Self::Drain<'_> {
+                return <$vec>::drain(self, range)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "allocator_api")]
+impl_vec! {
+    impl[K, V, A: crate::heap::alloc::Allocator] crate::heap::vec::Vec<(K, V), A> => {
+        type Alloc = A;
+        type Boxed = crate::heap::boxed::Box<[(K, V)], A>;
+        type Drain<'a> = crate::heap::vec::Drain<'a, (K, V), A> where Self: 'a;
+
+        #[inline]
+        fn with_capacity_in (capacity: usize, alloc: A) -> Self {
+            return Self::with_capacity_in(capacity, alloc)
+        }
+
+        #[inline]
+        fn allocator (&self) -> &A {
+            return crate::heap::vec::Vec::allocator(self)
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl_vec! {
+    impl[K, V] crate::heap::vec::Vec<(K, V)> => {
+        type Alloc = ();
+        type Boxed = crate::heap::boxed::Box<[(K, V)]>;
+        type Drain<'a> = crate::heap::vec::Drain<'a, (K, V)> where Self: 'a;
+
+        #[inline]
+        fn with_capacity_in (capacity: usize, _alloc: ()) -> Self {
+            return Self::with_capacity(capacity)
+        }
+
+        #[inline]
+        fn allocator (&self) -> &() {
+            return &()
+        }
+    }
+}
+
+/// # Safety
+/// The entries live inline, so the pointer stays valid up to `N` for as long as the vector isn't moved, and the
+/// insertions and removals shift the entries with `ptr::copy`, like `Vec` does.
+unsafe impl<K, V, const N: usize> MapStorage<K, V> for ArrayVec<(K, V), N> {
+    #[inline]
+    fn as_slice (&self) -> &[(K, V)] {
+        return ArrayVec::as_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_slice (&mut self) -> &mut [(K, V)] {
+        return ArrayVec::as_mut_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_ptr (&mut self) -> *mut (K, V) {
+        return ArrayVec::as_mut_ptr(self)
+    }
+
+    #[inline]
+    unsafe fn set_len (&mut self, len: usize) {
+        ArrayVec::set_len(self, len)
+    }
+
+    #[inline]
+    fn insert (&mut self, idx: usize, entry: (K, V)) -> Result<(), (K, V)> {
+        return ArrayVec::insert(self, idx, entry)
+    }
+
+    #[inline]
+    fn remove (&mut self, idx: usize) -> (K, V) {
+        return ArrayVec::remove(self, idx)
+    }
+
+    #[inline]
+    fn truncate (&mut self, len: usize) {
+        ArrayVec::truncate(self, len)
+    }
+
+    #[inline]
+    fn swap_remove (&mut self, idx: usize) -> (K, V) {
+        return ArrayVec::swap_remove(self, idx)
+    }
+}
+
+/// # Safety
+/// The pointer is the one of the borrowed buffer, valid up to its length, and the insertions and removals shift the
+/// entries with `ptr::copy`, like `Vec` does. The default `swap_remove` keeps the order of the remaining entries
+/// besides the swapped one.
+unsafe impl<K, V> MapStorage<K, V> for SliceVec<'_, (K, V)> {
+    #[inline]
+    fn as_slice (&self) -> &[(K, V)] {
+        return SliceVec::as_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_slice (&mut self) -> &mut [(K, V)] {
+        return SliceVec::as_mut_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_ptr (&mut self) -> *mut (K, V) {
+        return SliceVec::as_mut_ptr(self)
+    }
+
+    #[inline]
+    unsafe fn set_len (&mut self, len: usize) {
+        SliceVec::set_len(self, len)
+    }
+
+    #[inline]
+    fn insert (&mut self, idx: usize, entry: (K, V)) -> Result<(), (K, V)> {
+        return SliceVec::insert(self, idx, entry)
+    }
+
+    #[inline]
+    fn remove (&mut self, idx: usize) -> (K, V) {
+        return SliceVec::remove(self, idx)
+    }
+
+    #[inline]
+    fn truncate (&mut self, len: usize) {
+        SliceVec::truncate(self, len)
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+/// # Safety
+/// Every method forwards to its `heapless::Vec` counterpart, which stores its entries inline and mirrors the
+/// semantics of `Vec`.
+unsafe impl<K, V, const N: usize> MapStorage<K, V> for heapless::Vec<(K, V), N> {
+    #[inline]
+    fn as_slice (&self) -> &[(K, V)] {
+        return heapless::Vec::as_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_slice (&mut self) -> &mut [(K, V)] {
+        return heapless::Vec::as_mut_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_ptr (&mut self) -> *mut (K, V) {
+        return heapless::Vec::as_mut_ptr(self)
+    }
+
+    #[inline]
+    unsafe fn set_len (&mut self, len: usize) {
+        heapless::Vec::set_len(self, len)
+    }
+
+    #[inline]
+    fn insert (&mut self, idx: usize, entry: (K, V)) -> Result<(), (K, V)> {
+        return heapless::Vec::insert(self, idx, entry)
+    }
+
+    #[inline]
+    fn remove (&mut self, idx: usize) -> (K, V) {
+        return heapless::Vec::remove(self, idx)
+    }
+
+    #[inline]
+    fn truncate (&mut self, len: usize) {
+        heapless::Vec::truncate(self, len)
+    }
+
+    #[inline]
+    fn swap_remove (&mut self, idx: usize) -> (K, V) {
+        return heapless::Vec::swap_remove(self, idx)
+    }
+}
@@ -0,0 +1,134 @@
+use core::{mem::MaybeUninit, ptr, slice};
+
+/// A vector with a fixed capacity, stored in a buffer provided by the caller.
+///
+/// The buffer starts out empty, and the values left in it are dropped along with the vector.
+pub struct SliceVec<'a, T> {
+    len: usize,
+    data: &'a mut [MaybeUninit<T>],
+}
+
+impl<'a, T> SliceVec<'a, T> {
+    #[inline]
+    pub fn new (data: &'a mut [MaybeUninit<T>]) -> Self {
+        return Self { len: 0, data }
+    }
+
+    #[inline]
+    pub const fn len (&self) -> usize {
+        return self.len
+    }
+
+    #[inline]
+    pub const fn is_empty (&self) -> bool {
+        return self.len == 0
+    }
+
+    #[inline]
+    pub const fn capacity (&self) -> usize {
+        return self.data.len()
+    }
+
+    #[inline]
+    pub const fn is_full (&self) -> bool {
+        return self.len == self.data.len()
+    }
+
+    #[inline]
+    pub fn as_slice (&self) -> &[T] {
+        return unsafe { slice::from_raw_parts(self.data.as_ptr().cast(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice (&mut self) -> &mut [T] {
+        return unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_ptr (&mut self) -> *mut T {
+        return self.data.as_mut_ptr().cast()
+    }
+
+    /// # Safety
+    /// `len` must not exceed the capacity, and the first `len` values must be initialized.
+    #[inline]
+    pub unsafe fn set_len (&mut self, len: usize) {
+        debug_assert!(len <= self.data.len());
+        self.len = len
+    }
+
+    /// Inserts the value at `idx`, shifting the following ones to the right, or returns it back if the buffer is full.
+    #[inline]
+    pub fn insert (&mut self, idx: usize, value: T) -> Result<(), T> {
+        assert!(idx <= self.len, "insertion index (is {idx}) should be <= len (is {})", self.len);
+        if self.is_full() {
+            return Err(value)
+        }
+
+        unsafe {
+            let ptr = self.data.as_mut_ptr().add(idx);
+            ptr::copy(ptr, ptr.add(1), self.len - idx);
+            ptr.write(MaybeUninit::new(value));
+        }
+        self.len += 1;
+        return Ok(())
+    }
+
+    #[inline]
+    pub fn push (&mut self, value: T) -> Result<(), T> {
+        return self.insert(self.len, value)
+    }
+
+    #[inline]
+    pub fn pop (&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None
+        }
+
+        self.len -= 1;
+        return unsafe { Some(self.data.as_ptr().add(self.len).read().assume_init()) }
+    }
+
+    /// Removes the value at `idx`, shifting the following ones to the left.
+    #[inline]
+    pub fn remove (&mut self, idx: usize) -> T {
+        assert!(idx < self.len, "removal index (is {idx}) should be < len (is {})", self.len);
+        unsafe {
+            let ptr = self.data.as_mut_ptr().add(idx);
+            let value = ptr.read().assume_init();
+            ptr::copy(ptr.add(1), ptr, self.len - idx - 1);
+            self.len -= 1;
+            return value
+        }
+    }
+
+    #[inline]
+    pub fn truncate (&mut self, len: usize) {
+        if len >= self.len {
+            return
+        }
+
+        let tail = ptr::slice_from_raw_parts_mut(self.data[len..].as_mut_ptr().cast::<T>(), self.len - len);
+        self.len = len;
+        unsafe { ptr::drop_in_place(tail) }
+    }
+
+    #[inline]
+    pub fn clear (&mut self) {
+        self.truncate(0)
+    }
+}
+
+impl<T> Drop for SliceVec<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for SliceVec<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,147 @@
+use crate::heap::vec::Vec;
+use core::fmt::{Debug, Display};
+
+/// Decides what happens to entries whose key already appeared earlier when building a map out of a list of entries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DuplicatePolicy {
+    /// Keeps the first entry of every key, discarding the rest.
+    KeepFirst,
+    /// Keeps the value of the last entry of every key, in the position of the first one (the same result as
+    /// inserting the entries one by one).
+    #[default]
+    KeepLast,
+    /// Fails if any key appears more than once.
+    Error,
+}
+
+/// The error returned when building a map out of a list of entries that contains duplicate keys.
+/// Holds the indices of the entries whose key already appeared earlier, as well as the rejected list.
+pub struct DuplicateKeysError<T> {
+    indices: Vec<usize>,
+    value: T,
+}
+
+impl<T> DuplicateKeysError<T> {
+    #[inline]
+    pub(crate) fn new (indices: Vec<usize>, value: T) -> Self {
+        return Self { indices, value }
+    }
+
+    /// The indices of the entries whose key already appeared earlier, in ascending order.
+    #[inline]
+    pub fn indices (&self) -> &[usize] {
+        return &self.indices
+    }
+
+    /// Returns the rejected list of entries, untouched.
+    #[inline]
+    pub fn into_inner (self) -> T {
+        return self.value
+    }
+
+    #[inline]
+    pub(crate) fn map<U, F: FnOnce(T) -> U> (self, f: F) -> DuplicateKeysError<U> {
+        return DuplicateKeysError { indices: self.indices, value: f(self.value) }
+    }
+}
+
+impl<T> Debug for DuplicateKeysError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DuplicateKeysError").field("indices", &self.indices).finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for DuplicateKeysError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "found duplicate keys at indices {:?}", self.indices)
+    }
+}
+
+impl<T> core::error::Error for DuplicateKeysError<T> {}
+
+/// Finds every entry whose key already appeared earlier, as pairs of `(index, index of the first appearance)`,
+/// sorted by index. Runs in O(n²), since hashing the keys would need a hash set, which `alloc` lacks.
+#[inline]
+pub(crate) fn duplicates_eq<K: Eq, V> (entries: &[(K, V)]) -> Vec<(usize, usize)> {
+    let mut result = Vec::new();
+    for (i, (key, _)) in entries.iter().enumerate() {
+        if let Some(first) = entries[..i].iter().position(|(k, _)| k == key) {
+            result.push((i, first));
+        }
+    }
+    return result
+}
+
+/// Finds every entry whose key already appeared earlier, as pairs of `(index, index of the first appearance)`,
+/// sorted by index. Runs in O(n log n).
+#[inline]
+pub(crate) fn duplicates_ord<K: Ord, V> (entries: &[(K, V)]) -> Vec<(usize, usize)> {
+    // Stable, so that equal keys stay sorted by index
+    let mut order = (0..entries.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| entries[a].0.cmp(&entries[b].0));
+
+    let mut result = Vec::new();
+    let mut first = 0;
+    for i in 1..order.len() {
+        match entries[order[first]].0 == entries[order[i]].0 {
+            true => result.push((order[i], order[first])),
+            false => first = i,
+        }
+    }
+
+    result.sort_unstable();
+    return result
+}
+
+/// Applies the policy to the duplicates found by [`duplicates_eq`] or [`duplicates_ord`], moving the surviving
+/// entries to the front of the slice (in their original order) and returning how many of them there are.
+/// Returns the indices of the duplicates, leaving the slice untouched, if the policy is [`DuplicatePolicy::Error`].
+#[inline]
+pub(crate) fn resolve<K, V> (entries: &mut [(K, V)], duplicates: Vec<(usize, usize)>, policy: DuplicatePolicy) -> Result<usize, Vec<usize>> {
+    if duplicates.is_empty() {
+        return Ok(entries.len())
+    }
+
+    match policy {
+        DuplicatePolicy::Error => return Err(duplicates.into_iter().map(|(i, _)| i).collect()),
+        DuplicatePolicy::KeepFirst => {},
+        DuplicatePolicy::KeepLast => {
+            // Duplicates are sorted by index, so the first appearance ends up with the last value
+            for &(i, first) in duplicates.iter() {
+                let (head, tail) = entries.split_at_mut(i);
+                core::mem::swap(&mut head[first].1, &mut tail[0].1);
+            }
+        }
+    }
+
+    let mut duplicates = duplicates.into_iter().map(|(i, _)| i).peekable();
+    let mut len = 0;
+    for i in 0..entries.len() {
+        if duplicates.next_if_eq(&i).is_none() {
+            entries.swap(len, i);
+            len += 1;
+        }
+    }
+    return Ok(len)
+}
+
+/// Removes the entries of a sorted slice whose key is equal to the previous one, moving the surviving entries to
+/// the front of the slice and returning how many of them there are. Every run of equal keys keeps its first entry,
+/// with the value of its last one if `keep_last` is set.
+#[inline]
+pub(crate) fn dedup_sorted<K: Eq, V> (entries: &mut [(K, V)], keep_last: bool) -> usize {
+    let mut len = 0;
+    for i in 0..entries.len() {
+        if len > 0 && entries[len - 1].0 == entries[i].0 {
+            if keep_last {
+                let (head, tail) = entries.split_at_mut(i);
+                core::mem::swap(&mut head[len - 1].1, &mut tail[0].1);
+            }
+            continue
+        }
+
+        entries.swap(len, i);
+        len += 1;
+    }
+    return len
+}
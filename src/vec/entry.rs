@@ -1,111 +1,313 @@
-#[cfg(feature = "alloc")]
-use core::alloc::*;
-#[cfg(feature = "alloc")]
-use alloc::alloc::*;
 use core::{
-    mem::{MaybeUninit},
+    fmt::{Debug, Display},
+    marker::PhantomData,
 };
-use alloc::vec::*;
+use super::{OrderPolicy, Unordered};
+use crate::storage::MapStorage;
+#[cfg(feature = "alloc")]
+use crate::{heap::{collections::TryReserveError, vec::Vec}, storage::HeapStorage};
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        pub enum Entry<'a, K: 'a, V: 'a, A: Allocator = Global> {
-            Occupied(OccupiedEntry<'a, K, V, A>),
-            Vacant(VacantEntry<'a, K, V, A>),
+pub enum Entry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S, P: OrderPolicy = Unordered> {
+    Occupied(OccupiedEntry<'a, K, V, S, P>),
+    Vacant(VacantEntry<'a, K, V, S, P>),
+}
+
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy> Entry<'a, K, V, S, P> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
         }
-    } else {
-        pub enum Entry<'a, K: 'a, V: 'a> {
-            Occupied(OccupiedEntry<'a, K, V>),
-            Vacant(VacantEntry<'a, K, V>),
+    }
+
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(entry.get_mut())
         }
+        self
     }
-}
 
-macro_rules! impl_all {
-    ($name:ident => { $($t:tt)* }) => {
-        #[cfg(feature = "alloc")]
-        impl<'a, K, V, A: Allocator> $name<'a, K, V, A> {
-            $($t)*
+    /// Same as `or_insert`, but returns the entry back if the key is new and the storage is full.
+    #[inline]
+    pub fn checked_or_insert(self, default: V) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.checked_insert(default),
         }
+    }
 
-        #[cfg(not(feature = "alloc"))]
-        impl<'a, K, V> $name<'a, K, V> {
-            $($t)*
+    #[inline]
+    pub fn checked_or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.checked_insert(default()),
         }
-    };
-}
+    }
 
-#[derive(Debug)]
-pub struct OccupiedEntry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
-    pub(super) parent: &'a mut Vec<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    pub(super) parent: &'a mut Vec<(K, V)>,
-    pub(super) idx: usize,
+    #[inline]
+    pub fn checked_or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.checked_insert(value)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn checked_or_default(self) -> Result<&'a mut V, (K, V)> where V: Default {
+        self.checked_or_insert_with(V::default)
+    }
 }
 
-impl_all! {
-    OccupiedEntry => {
-        #[inline]
-        pub fn get(&self) -> &V {
-            unsafe { &self.parent.get_unchecked(self.idx).1 }
+#[docfg::docfg(feature = "alloc")]
+impl<'a, K, V, S: HeapStorage<K, V>, P: OrderPolicy> Entry<'a, K, V, S, P> {
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
         }
+    }
 
-        #[inline]
-        pub fn get_mut(&mut self) -> &mut V {
-            unsafe { &mut self.parent.get_unchecked_mut(self.idx).1 }
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
         }
+    }
 
-        #[inline]
-        pub fn into_mut(self) -> &'a mut V {
-            unsafe { &mut self.parent.get_unchecked_mut(self.idx).1 }
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
         }
+    }
 
-        #[inline]
-        pub fn insert(&mut self, value: V) -> V {
-            core::mem::replace(self.get_mut(), value)
+    #[inline]
+    pub fn or_default(self) -> &'a mut V where V: Default {
+        self.or_insert_with(V::default)
+    }
+
+    /// Sets the value of the entry, and returns an [`OccupiedEntry`] pointing to it.
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, S, P, ()> {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                OccupiedEntry { parent: entry.parent, idx: entry.idx, key: (), _marker: PhantomData }
+            },
+            Entry::Vacant(entry) => entry.insert_entry(value),
         }
+    }
+}
+
+/// An entry of a key present in the map.
+///
+/// `Q` is the key the entry was looked up with by [`entry`](super::VecMap::entry), which the `replace_*` methods
+/// swap in. The entries that weren't looked up by key, like the ones returned by `insert_entry`, hold `()` instead.
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S, P: OrderPolicy = Unordered, Q = K> {
+    pub(super) parent: &'a mut S,
+    pub(super) idx: usize,
+    pub(super) key: Q,
+    pub(super) _marker: PhantomData<(K, V, P)>,
+}
+
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy, Q> OccupiedEntry<'a, K, V, S, P, Q> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        unsafe { &self.parent.as_slice().get_unchecked(self.idx).0 }
+    }
+
+    #[inline]
+    pub fn get(&self) -> &V {
+        unsafe { &self.parent.as_slice().get_unchecked(self.idx).1 }
+    }
 
-        #[inline]
-        pub fn remove(self) -> V {
-            self.parent.swap_remove(self.idx).1
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut self.parent.as_mut_slice().get_unchecked_mut(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut self.parent.as_mut_slice().get_unchecked_mut(self.idx).1 }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        match P::PRESERVE_ORDER {
+            true => self.shift_remove_entry(),
+            false => self.swap_remove_entry()
         }
     }
+
+    #[inline]
+    pub fn swap_remove(self) -> V {
+        self.swap_remove_entry().1
+    }
+
+    #[inline]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.parent.swap_remove(self.idx)
+    }
+
+    #[inline]
+    pub fn shift_remove(self) -> V {
+        self.shift_remove_entry().1
+    }
+
+    #[inline]
+    pub fn shift_remove_entry(self) -> (K, V) {
+        self.parent.remove(self.idx)
+    }
+}
+
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy> OccupiedEntry<'a, K, V, S, P> {
+    /// Replaces the entry's key with the one it was looked up with, and its value with `value`,
+    /// returning the previous key and value.
+    #[inline]
+    pub fn replace_entry(self, value: V) -> (K, V) {
+        let entry = unsafe { self.parent.as_mut_slice().get_unchecked_mut(self.idx) };
+        return core::mem::replace(entry, (self.key, value))
+    }
+
+    /// Replaces the entry's key with the one it was looked up with, returning the previous key.
+    #[inline]
+    pub fn replace_key(self) -> K {
+        let entry = unsafe { self.parent.as_mut_slice().get_unchecked_mut(self.idx) };
+        return core::mem::replace(&mut entry.0, self.key)
+    }
 }
 
 #[derive(Debug)]
-pub struct VacantEntry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
-    pub(super) parent: &'a mut Vec<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    pub(super) parent: &'a mut Vec<(K, V)>,
+pub struct VacantEntry<'a, K: 'a, V: 'a, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S, P: OrderPolicy = Unordered> {
+    pub(super) parent: &'a mut S,
     pub(super) key: K,
+    pub(super) _marker: PhantomData<(V, P)>,
 }
 
-impl_all! {
-    VacantEntry => {
-        #[inline]
-        pub fn insert(self, value: V) -> &'a mut V {
-            unsafe {
-                self.parent.reserve(1);
-                let entry = &mut *self.parent
-                    .as_mut_ptr()
-                    .add(self.parent.len())
-                    .cast::<MaybeUninit<(K, V)>>();
-                
-                self.parent.set_len(self.parent.len() + 1);
-                return &mut entry.write((self.key, value)).1
-            }
-        }
-        
-        #[inline]
-        pub fn key(&self) -> &K {
-            return &self.key;
-        }
-        
-        #[inline]
-        pub fn into_key(self) -> K {
-            return self.key;
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy> VacantEntry<'a, K, V, S, P> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        return &self.key;
+    }
+
+    #[inline]
+    pub fn into_key(self) -> K {
+        return self.key;
+    }
+
+    /// Inserts the entry at the back of the map, or returns it back if the storage is full.
+    #[inline]
+    pub fn checked_insert(self, value: V) -> Result<&'a mut V, (K, V)> {
+        let idx = self.parent.len();
+        self.parent.insert(idx, (self.key, value))?;
+        return unsafe { Ok(&mut self.parent.as_mut_slice().get_unchecked_mut(idx).1) }
+    }
+}
+
+#[docfg::docfg(feature = "alloc")]
+impl<'a, K, V, S: HeapStorage<K, V>, P: OrderPolicy> VacantEntry<'a, K, V, S, P> {
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let idx = self.parent.len();
+        self.parent.insert_growing(idx, (self.key, value));
+        return unsafe { &mut self.parent.as_mut_slice().get_unchecked_mut(idx).1 }
+    }
+
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the entry, handing the value back alongside it.
+    #[inline]
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, (TryReserveError, V)> {
+        if let Err(e) = self.parent.try_reserve(1) {
+            return Err((e, value))
         }
+        return Ok(self.insert(value))
     }
-}
\ No newline at end of file
+
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, S, P, ()> {
+        let idx = self.parent.len();
+        self.parent.insert_growing(idx, (self.key, value));
+        return OccupiedEntry { parent: self.parent, idx, key: (), _marker: PhantomData }
+    }
+}
+
+/// The error returned by `try_insert` when the key is already present in the map.
+/// Holds the entry of the existing key, as well as the value that wasn't inserted.
+///
+/// Every map reports its conflicts with this type, parameterized by its own occupied entry.
+pub struct OccupiedError<E: sealed::Occupied> {
+    pub entry: E,
+    pub value: E::Value,
+}
+
+impl<E: sealed::Occupied> Debug for OccupiedError<E> where E::Key: Debug, E::Value: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OccupiedError")
+            .field("key", self.entry.key())
+            .field("old_value", self.entry.get())
+            .field("new_value", &self.value)
+            .finish()
+    }
+}
+
+impl<E: sealed::Occupied> Display for OccupiedError<E> where E::Key: Debug, E::Value: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "failed to insert {:?}, key {:?} already exists with value {:?}",
+            self.value,
+            self.entry.key(),
+            self.entry.get(),
+        )
+    }
+}
+
+impl<E: sealed::Occupied> core::error::Error for OccupiedError<E> where E::Key: Debug, E::Value: Debug {}
+
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy, Q> sealed::Occupied for OccupiedEntry<'a, K, V, S, P, Q> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn key(&self) -> &K {
+        return OccupiedEntry::key(self)
+    }
+
+    #[inline]
+    fn get(&self) -> &V {
+        return OccupiedEntry::get(self)
+    }
+}
+
+pub(crate) mod sealed {
+    /// The occupied entries that an [`OccupiedError`](super::OccupiedError) can hold.
+    pub trait Occupied {
+        type Key;
+        type Value;
+
+        fn key(&self) -> &Self::Key;
+        fn get(&self) -> &Self::Value;
+    }
+}
@@ -0,0 +1,151 @@
+use super::{OrderPolicy, VecMap};
+use crate::storage::MapStorage;
+use core::marker::PhantomData;
+
+pub(crate) use self::sealed::Compact;
+
+/// An iterator that removes (and yields) the entries that match a predicate,
+/// keeping the relative order of the remaining ones.
+///
+/// If the iterator is dropped before being fully consumed, the remaining entries are kept.
+pub struct ExtractIf<'a, K, V, F, S: Compact<K, V>> {
+    vec: &'a mut S,
+    idx: usize,
+    del: usize,
+    old_len: usize,
+    pred: F,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<'a, K, V, F, S: Compact<K, V>> ExtractIf<'a, K, V, F, S> {
+    #[inline]
+    pub(crate) fn new(vec: &'a mut S, pred: F) -> Self {
+        let old_len = vec.len();
+        // Guard against leaks: if the iterator is forgotten, the entries are leaked instead of double-dropped.
+        unsafe { vec.set_len(0) };
+        return Self { vec, idx: 0, del: 0, old_len, pred, _marker: PhantomData }
+    }
+
+    #[inline]
+    fn next_entry(&mut self) -> Option<(K, V)> where F: FnMut(&K, &mut V) -> bool {
+        unsafe {
+            while self.idx < self.old_len {
+                let (key, value) = self.vec.entry(self.idx);
+                let drained = (self.pred)(&*key, &mut *value);
+                // Only advance once the predicate has returned, so that a panic leaves the entry in place
+                self.idx += 1;
+
+                if drained {
+                    self.del += 1;
+                    return Some((core::ptr::read(key), core::ptr::read(value)));
+                } else if self.del > 0 {
+                    self.vec.copy(self.idx - 1, self.idx - 1 - self.del, 1);
+                }
+            }
+            return None
+        }
+    }
+
+    #[inline]
+    fn finish(&mut self) {
+        unsafe {
+            if self.idx < self.old_len && self.del > 0 {
+                self.vec.copy(self.idx, self.idx - self.del, self.old_len - self.idx);
+            }
+            self.vec.set_len(self.old_len - self.del);
+        }
+    }
+}
+
+impl<'a, K, V, F: FnMut(&K, &mut V) -> bool, S: Compact<K, V>> Iterator for ExtractIf<'a, K, V, F, S> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_entry()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<'a, K, V, F, S: Compact<K, V>> Drop for ExtractIf<'a, K, V, F, S> {
+    #[inline]
+    fn drop(&mut self) {
+        self.finish()
+    }
+}
+
+/// # Safety
+/// Forwards to the storage, whose pointer stays valid up to its capacity.
+unsafe impl<K, V, S: MapStorage<K, V>, P: OrderPolicy> Compact<K, V> for VecMap<K, V, S, P> {
+    #[inline]
+    fn len (&self) -> usize {
+        return MapStorage::len(&self.inner)
+    }
+
+    #[inline]
+    unsafe fn set_len (&mut self, len: usize) {
+        MapStorage::set_len(&mut self.inner, len)
+    }
+
+    #[inline]
+    unsafe fn entry (&mut self, idx: usize) -> (*mut K, *mut V) {
+        return storage_entry(&mut self.inner, idx)
+    }
+
+    #[inline]
+    unsafe fn copy (&mut self, src: usize, dst: usize, count: usize) {
+        storage_copy(&mut self.inner, src, dst, count)
+    }
+}
+
+/// Returns pointers to the key and the value of the entry at `idx` of the storage.
+///
+/// # Safety
+/// `idx` must be below the capacity of the storage.
+#[inline]
+pub(crate) unsafe fn storage_entry<K, V, S: MapStorage<K, V>> (storage: &mut S, idx: usize) -> (*mut K, *mut V) {
+    let entry = storage.as_mut_ptr().add(idx);
+    return (core::ptr::addr_of_mut!((*entry).0), core::ptr::addr_of_mut!((*entry).1))
+}
+
+/// Copies `count` entries of the storage from `src` to `dst`. The ranges may overlap.
+///
+/// # Safety
+/// Both ranges must be below the capacity of the storage.
+#[inline]
+pub(crate) unsafe fn storage_copy<K, V, S: MapStorage<K, V>> (storage: &mut S, src: usize, dst: usize, count: usize) {
+    let ptr = storage.as_mut_ptr();
+    core::ptr::copy(ptr.add(src), ptr.add(dst), count)
+}
+
+mod sealed {
+    /// The entries an [`ExtractIf`](super::ExtractIf) compacts in place, which may be stored together or in
+    /// separate buffers.
+    ///
+    /// # Safety
+    /// The pointers returned by `entry` must stay valid for reads and writes up to the length the entries had
+    /// when the iterator was created, for as long as it's alive.
+    pub unsafe trait Compact<K, V> {
+        fn len (&self) -> usize;
+
+        /// # Safety
+        /// Same as [`MapStorage::set_len`](crate::storage::MapStorage::set_len).
+        unsafe fn set_len (&mut self, len: usize);
+
+        /// Returns pointers to the key and the value at `idx`, which may be past the length of the entries.
+        ///
+        /// # Safety
+        /// `idx` must be below the length the entries had when the iterator was created.
+        unsafe fn entry (&mut self, idx: usize) -> (*mut K, *mut V);
+
+        /// Copies `count` entries from `src` to `dst`. The ranges may overlap.
+        ///
+        /// # Safety
+        /// Both ranges must be below the length the entries had when the iterator was created.
+        unsafe fn copy (&mut self, src: usize, dst: usize, count: usize);
+    }
+}
@@ -0,0 +1,28 @@
+/// Resolves every key into the index of its entry, or returns `None` if any of them is missing.
+#[inline]
+pub(crate) fn indices<Q: ?Sized, F: FnMut(&Q) -> Option<usize>, const N: usize> (keys: [&Q; N], mut f: F) -> Option<[usize; N]> {
+    let mut result = [0; N];
+    for (idx, key) in result.iter_mut().zip(keys) {
+        *idx = f(key)?;
+    }
+    return Some(result)
+}
+
+/// Checks that no index is repeated.
+#[inline]
+pub(crate) fn is_disjoint (indices: &[usize]) -> bool {
+    return (1..indices.len()).all(|i| !indices[..i].contains(&indices[i]))
+}
+
+#[inline]
+pub(crate) fn values<K, V, const N: usize> (entries: &[(K, V)], indices: [usize; N]) -> [&V; N] {
+    return indices.map(|i| &entries[i].1)
+}
+
+/// # Safety
+/// All the indices must be in bounds, and different from each other.
+#[inline]
+pub(crate) unsafe fn values_mut<K, V, const N: usize> (entries: &mut [(K, V)], indices: [usize; N]) -> [&mut V; N] {
+    let ptr = entries.as_mut_ptr();
+    return indices.map(|i| unsafe { &mut (*ptr.add(i)).1 })
+}
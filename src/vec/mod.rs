@@ -1,79 +1,148 @@
-macro_rules! impl_all {
-    ($(where $($trait:path),+ =>)? { $($t:tt)* }) => {
-        #[cfg(feature = "alloc")]
-        impl<K, V, A: Allocator> VecMap<K, V, A> $(where K: $($trait+)*)? {
-            $($t)*
-        }
-
-        #[cfg(not(feature = "alloc"))]
-        impl<K, V> VecMap<K, V> $(where K: $($trait+)*)? {
-            $($t)*
-        }
-    };
-}
-
-flat_mod! { entry }
+flat_mod! { entry, extract_if, policy, ops, self_organizing }
+pub(crate) mod many;
+#[cfg(feature = "alloc")]
+pub(crate) mod dedup;
+#[cfg(feature = "alloc")]
+pub use dedup::{DuplicatePolicy, DuplicateKeysError};
+pub use crate::slice::{Iter, IterMut, Keys, Values, ValuesMut};
 
 use docfg::docfg;
+#[cfg(feature = "allocator_api")]
+use crate::heap::alloc::*;
+use core::{borrow::Borrow, cmp::Ordering, fmt::Debug, marker::PhantomData, ops::{Index, IndexMut}};
 #[cfg(feature = "alloc")]
-use core::alloc::*;
-#[cfg(feature = "alloc")]
-use alloc::alloc::*;
-use core::{borrow::Borrow, fmt::Debug, ops::{Index, IndexMut}};
-use alloc::{vec::*, boxed::Box};
-use crate::r#box::BoxMap;
+use crate::{heap::{collections::TryReserveError, vec::Vec}, storage::HeapStorage};
+use crate::{binary::BinaryMap, storage::MapStorage};
+use crate::search::SearchKey;
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        pub type IntoIter<K, V, A = Global> = alloc::vec::IntoIter<(K, V), A>;
-        pub type Drain<'a, K, V, A = Global> = alloc::vec::Drain<'a, (K, V), A>;
-    } else {
-        pub type IntoIter<K, V> = alloc::vec::IntoIter<(K, V)>;
-        pub type Drain<'a, K, V> = alloc::vec::Drain<'a, (K, V)>;
+    if #[cfg(feature = "allocator_api")] {
+        pub type IntoIter<K, V, A = Global> = crate::heap::vec::IntoIter<(K, V), A>;
+        pub type Drain<'a, K, V, A = Global> = crate::heap::vec::Drain<'a, (K, V), A>;
+    } else if #[cfg(feature = "alloc")] {
+        pub type IntoIter<K, V> = crate::heap::vec::IntoIter<(K, V)>;
+        pub type Drain<'a, K, V> = crate::heap::vec::Drain<'a, (K, V)>;
     }
 }
 
+pub type OrderedVecMap<K, V, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S> = VecMap<K, V, S, Ordered>;
+
+/// A map that keeps its entries in a [`MapStorage`], and searches them linearly.
+///
+/// The storage is a `Vec` by default, but any other [`MapStorage`] works, like an [`ArrayVec`](crate::array::ArrayVec)
+/// that never allocates. Growing the map on demand, as [`insert`](VecMap::insert) does, needs a [`HeapStorage`],
+/// while [`checked_insert`](VecMap::checked_insert) works on any storage, returning the entry back if it's full.
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
-pub struct VecMap<K, V, #[cfg(feature = "alloc")] A: Allocator = Global> {
-    #[cfg(feature = "alloc")]
-    inner: Vec<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    inner: Vec<(K, V)>,
+pub struct VecMap<K, V, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S, P: OrderPolicy = Unordered> {
+    inner: S,
+    _marker: PhantomData<(K, V, P)>,
 }
 
+#[cfg(feature = "alloc")]
 impl<K, V> VecMap<K, V> {
     #[inline]
     pub const fn new() -> Self {
-        return Self { inner: Vec::new() };
+        return Self::with_policy(Unordered);
     }
 
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
+        return Self::with_capacity_and_policy(capacity, Unordered);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V, P: OrderPolicy> VecMap<K, V, Vec<(K, V)>, P> {
+    #[inline]
+    pub const fn with_policy(_policy: P) -> Self {
+        return Self { inner: Vec::new(), _marker: PhantomData };
+    }
+
+    #[inline]
+    pub fn with_capacity_and_policy(capacity: usize, _policy: P) -> Self {
         return Self {
             inner: Vec::with_capacity(capacity),
+            _marker: PhantomData,
         };
     }
 }
 
-#[docfg(feature = "alloc")]
-impl<K, V, A: Allocator> VecMap<K, V, A> {
+#[docfg(feature = "allocator_api")]
+impl<K, V, A: Allocator> VecMap<K, V, Vec<(K, V), A>> {
     #[inline]
     pub const fn new_in(alloc: A) -> Self {
+        return Self::with_policy_in(Unordered, alloc);
+    }
+
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        return Self::with_capacity_and_policy_in(capacity, Unordered, alloc);
+    }
+}
+
+#[docfg(feature = "allocator_api")]
+impl<K, V, A: Allocator, P: OrderPolicy> VecMap<K, V, Vec<(K, V), A>, P> {
+    #[inline]
+    pub const fn with_policy_in(_policy: P, alloc: A) -> Self {
         return Self {
             inner: Vec::new_in(alloc),
+            _marker: PhantomData,
         };
     }
 
     #[inline]
-    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+    pub fn with_capacity_and_policy_in(capacity: usize, _policy: P, alloc: A) -> Self {
         return Self {
             inner: Vec::with_capacity_in(capacity, alloc),
+            _marker: PhantomData,
         };
     }
 }
 
-impl_all! {{
+impl<K, V, S: MapStorage<K, V>> VecMap<K, V, S> {
+    /// Builds a map out of a storage, or returns it back if it contains duplicate keys. Runs in O(n²).
+    /// Unlike [`from_vec`](Self::from_vec), it's available without the `alloc` feature.
+    #[inline]
+    pub fn from_storage (storage: S) -> Result<Self, S> where K: Eq {
+        let entries = storage.as_slice();
+        if (1..entries.len()).any(|i| K::position(&entries[..i], &entries[i].0, |(k, _)| k).is_some()) {
+            return Err(storage)
+        }
+        return Ok(Self { inner: storage, _marker: PhantomData })
+    }
+}
+
+impl<K, V, S: MapStorage<K, V>, P: OrderPolicy> VecMap<K, V, S, P> {
+    /// # Safety
+    /// The keys must be unique.
+    #[inline]
+    pub const unsafe fn from_vec_unchecked (vec: S) -> Self {
+        return Self { inner: vec, _marker: PhantomData }
+    }
+
+    #[inline]
+    pub fn storage (&self) -> &S {
+        return &self.inner
+    }
+
+    #[inline]
+    pub fn into_vec (self) -> S {
+        return self.inner
+    }
+
+    #[inline]
+    pub fn into_policy<Q: OrderPolicy> (self) -> VecMap<K, V, S, Q> {
+        return VecMap { inner: self.inner, _marker: PhantomData }
+    }
+
+    /// Converts the map into a [`BinaryMap`] without reallocating, or returns it back if its keys aren't sorted.
+    #[inline]
+    pub fn into_binary_map (self) -> Result<BinaryMap<K, V, S>, Self> where K: Ord {
+        if !self.is_sorted_by_key() { return Err(self) }
+        return Ok(unsafe { BinaryMap::from_vec_unchecked(self.inner) })
+    }
+
     #[inline]
     pub fn len (&self) -> usize {
         return self.inner.len();
@@ -84,713 +153,616 @@ impl_all! {{
         return self.inner.is_empty();
     }
 
-    #[inline]
-    pub fn capacity (&self) -> usize {
-        return self.inner.capacity()
-    }
-
     #[inline]
     pub fn clear (&mut self) {
         self.inner.clear()
     }
-}}
 
-impl_all! {
-    {
-        #[inline]
-        pub fn get<Q: ?Sized + Eq> (&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
-            for (k, v) in self.inner.iter() {
-                if k.borrow() == key { return Some(v) }
-            }
-            return None
-        }
-
-        #[inline]
-        pub fn get_key_value<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
-            for (k, v) in self.inner.iter() {
-                if k.borrow() == key { return Some((k, v)) }
-            }
-            return None
-        }
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool> (&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop)
+    }
 
-        #[inline]
-        pub fn get_mut<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
-            for (k, v) in self.inner.iter_mut() {
-                if <K as Borrow<Q>>::borrow(k) == key { return Some(v) }
-            }
-            return None
-        }
-        
-        #[inline]
-        pub fn remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
-            return self.remove_entry(key).map(|x| x.1)
-        }
-        
-        #[inline]
-        pub fn remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
-            for i in 0..self.len() {
-                if unsafe { <K as Borrow<Q>>::borrow(&self.inner.get_unchecked(i).0) } == key {
-                    return Some(self.inner.swap_remove(i))
-                }
-            }
-            return None
-        }
-        
-        #[inline]
-        pub fn contains_key<Q: ?Sized + Eq> (&self, k: &Q) -> bool where K: Borrow<Q> {
-            self.keys().any(|x| x.borrow() == k)
-        }
+    #[inline]
+    pub fn get<Q: ?Sized + Eq> (&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(&self.inner.as_slice().get_unchecked(idx).1) }
     }
-}
 
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        let (k, v) = unsafe { self.inner.as_slice().get_unchecked(idx) };
+        return Some((k, v))
+    }
 
-impl_all! {
-    where Eq => {
-        #[inline]
-        pub fn insert (&mut self, key: K, value: V) -> Option<V> {
-            return match self.entry(key) {
-                Entry::Occupied(mut x) => Some(x.insert(value)),
-                Entry::Vacant(x) => {
-                    x.insert(value);
-                    None
-                }
-            }
-        }
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return unsafe { Some(&mut self.inner.as_mut_slice().get_unchecked_mut(idx).1) }
+    }
 
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.remove_entry(key).map(|x| x.1)
     }
-}
 
-impl_all! {{
     #[inline]
-    pub fn iter (&self) -> Iter<'_, K, V> {
-        return Iter(self.inner.iter())
+    pub fn remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        return match P::PRESERVE_ORDER {
+            true => self.shift_remove_entry(key),
+            false => self.swap_remove_entry(key)
+        }
     }
 
     #[inline]
-    pub fn iter_mut (&mut self) -> IterMut<'_, K, V> {
-        return IterMut(self.inner.iter_mut())
+    pub fn swap_remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.swap_remove_entry(key).map(|x| x.1)
     }
-    
+
     #[inline]
-    pub fn keys (&self) -> Keys<'_, K, V> {
-        return Keys(self.inner.iter())
+    pub fn swap_remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return Some(self.inner.swap_remove(idx))
     }
 
     #[inline]
-    pub fn values (&self) -> Values<'_, K, V> {
-        return Values(self.inner.iter())
+    pub fn shift_remove<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.shift_remove_entry(key).map(|x| x.1)
     }
 
     #[inline]
-    pub fn values_mut (&mut self) -> ValuesMut<'_, K, V> {
-        return ValuesMut(self.inner.iter_mut())
+    pub fn shift_remove_entry<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        return Some(self.inner.remove(idx))
     }
-}}
 
-#[cfg(feature = "alloc")]
-impl<K, V, A: Allocator> VecMap<K, V, A> {
     #[inline]
-    pub unsafe fn from_vec_unchecked (vec: Vec<(K, V), A>) -> Self {
-        return Self { inner: vec }
+    pub fn contains_key<Q: ?Sized + Eq> (&self, k: &Q) -> bool where K: Borrow<Q> {
+        self.get_index_of(k).is_some()
     }
 
+    /// Returns references to the values of all the keys, or `None` if any of them is missing.
     #[inline]
-    pub unsafe fn from_box_unchecked (bx: Box<[(K, V)], A>) -> Self {
-        return Self::from_vec_unchecked(bx.into_vec())
+    pub fn get_many<Q: ?Sized + Eq, const N: usize> (&self, keys: [&Q; N]) -> Option<[&V; N]> where K: Borrow<Q> {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        return Some(many::values(self.inner.as_slice(), indices))
     }
 
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing
+    /// or if two of them refer to the same entry.
     #[inline]
-    pub fn into_vec (self) -> Vec<(K, V), A> {
-        return self.inner
+    pub fn get_many_mut<Q: ?Sized + Eq, const N: usize> (&mut self, keys: [&Q; N]) -> Option<[&mut V; N]> where K: Borrow<Q> {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        if !many::is_disjoint(&indices) { return None }
+        return unsafe { Some(many::values_mut(self.inner.as_mut_slice(), indices)) }
     }
 
+    /// Returns mutable references to the values of all the keys, or `None` if any of them is missing.
+    ///
+    /// # Safety
+    /// No two keys may refer to the same entry.
     #[inline]
-    pub fn into_box (self) -> Box<[(K, V)], A> {
-        return self.inner.into_boxed_slice()
+    pub unsafe fn get_many_unchecked_mut<Q: ?Sized + Eq, const N: usize> (&mut self, keys: [&Q; N]) -> Option<[&mut V; N]> where K: Borrow<Q> {
+        let indices = many::indices(keys, |key| self.get_index_of(key))?;
+        debug_assert!(many::is_disjoint(&indices));
+        return Some(many::values_mut(self.inner.as_mut_slice(), indices))
     }
 
     #[inline]
-    pub fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, A> where K: Eq {
-        for i in 0..self.inner.len() {
-            if unsafe { &self.inner.get_unchecked(i).0 } == &key {
-                return Entry::Occupied(OccupiedEntry {
-                    parent: &mut self.inner,
-                    idx: i,
-                });
-            }
+    pub fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, S, P> where K: Eq {
+        if let Some(idx) = K::position(self.inner.as_slice(), &key, |(k, _)| k) {
+            return Entry::Occupied(OccupiedEntry {
+                parent: &mut self.inner,
+                idx,
+                key,
+                _marker: PhantomData,
+            });
         }
         return Entry::Vacant(VacantEntry {
             parent: &mut self.inner,
             key,
+            _marker: PhantomData,
         });
     }
 
+    /// Inserts the entry, returning the previous value of the key. Unlike [`insert`](Self::insert), it works
+    /// on any storage, returning the entry back if the key is new and the storage is full.
     #[inline]
-    pub fn into_keys (self) -> IntoKeys<K, V, A> {
-        return IntoKeys(self.inner.into_iter())
+    pub fn checked_insert (&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> where K: Eq {
+        return match self.entry(key) {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => entry.checked_insert(value).map(|_| None)
+        }
     }
 
+    /// Inserts every entry with [`checked_insert`](Self::checked_insert), stopping at the first one that doesn't fit,
+    /// which is returned back. The entries inserted before it are kept.
     #[inline]
-    pub fn into_values (self) -> IntoValues<K, V, A> {
-        return IntoValues(self.inner.into_iter())
+    pub fn checked_extend<I: IntoIterator<Item = (K, V)>> (&mut self, iter: I) -> Result<(), (K, V)> where K: Eq {
+        for (key, value) in iter {
+            self.checked_insert(key, value)?;
+        }
+        return Ok(())
     }
 
     #[inline]
-    pub fn drain (&mut self) -> Drain<'_, K, V, A> {
-        return self.inner.drain(..)
+    pub fn get_index_of<Q: ?Sized + Eq> (&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        return Q::position(self.inner.as_slice(), key, |(k, _)| k)
     }
-}
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        impl<K, V, A: Allocator> IntoIterator for VecMap<K, V, A> {
-            type Item = (K, V);
-            type IntoIter = IntoIter<K, V, A>;
-        
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                self.inner.into_iter()
-            }
-        }
-        
-        impl<'a, K, V, A: Allocator> IntoIterator for &'a VecMap<K, V, A> {
-            type Item = (&'a K, &'a V);
-            type IntoIter = Iter<'a, K, V>;
-        
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                VecMap::iter(self)
-            }
-        }
-        
-        impl<'a, K, V, A: Allocator> IntoIterator for &'a mut VecMap<K, V, A> {
-            type Item = (&'a K, &'a mut V);
-            type IntoIter = IterMut<'a, K, V>;
-        
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                VecMap::iter_mut(self)
-            }
-        }
-        
-        impl<K: Eq, V, A: Allocator> Extend<(K, V)> for VecMap<K, V, A> {
-            #[inline]
-            fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
-                for (key, value) in iter {
-                    let _ = self.insert(key, value);
-                }
-            }
-        }
-        
-        impl<K: Eq, V, A: Allocator + Default> FromIterator<(K, V)> for VecMap<K, V, A> {
-            #[inline]
-            fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
-                let iter = iter.into_iter();
-                let mut result = Self::with_capacity_in(match iter.size_hint() {
-                    (_, Some(x)) => x,
-                    (x, _) => x
-                }, A::default());
-        
-                result.extend(iter);
-                return result
-            }
-        }
-        
-        impl<K, V, A: Allocator + Default> Default for VecMap<K, V, A> {
-            #[inline]
-            fn default() -> Self {
-                Self { inner: Vec::new_in(A::default()) }
-            }
-        }
-        
-        impl<K, V, A: Allocator> From<BoxMap<K, V, A>> for VecMap<K, V, A> {
-            #[inline]
-            fn from(value: BoxMap<K, V, A>) -> Self {
-                Self { inner: value.into_vec() }
-            }
-        }
-        
-        
-        impl<K: Debug, V: Debug, A: Allocator> Debug for VecMap<K, V, A> {
-            #[inline]
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                f.debug_map().entries(self.iter()).finish()
-            }
-        }
-        
-        impl<Q: ?Sized + Eq, K: Borrow<Q>, V, A: Allocator> Index<&Q> for VecMap<K, V, A> {
-            type Output = V;
-        
-            #[inline]
-            fn index(&self, index: &Q) -> &Self::Output {
-                self.get(index).expect("index not found")
-            }
-        }
-        
-        impl<Q: ?Sized + Eq, K: Borrow<Q>, V, A: Allocator> IndexMut<&Q> for VecMap<K, V, A> {
-            #[inline]
-            fn index_mut (&mut self, index: &Q) -> &mut Self::Output {
-                self.get_mut(index).expect("index not found")
-            }
-        }
-    } else {
-        impl<K, V> VecMap<K, V> {
-            #[inline]
-            pub unsafe fn from_vec_unchecked (vec: Vec<(K, V)>) -> Self {
-                return Self { inner: vec }
-            }
+    #[inline]
+    pub fn get_full<Q: ?Sized + Eq> (&self, key: &Q) -> Option<(usize, &K, &V)> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        let (k, v) = unsafe { self.inner.as_slice().get_unchecked(idx) };
+        return Some((idx, k, v))
+    }
 
-            #[inline]
-            pub unsafe fn from_box_unchecked (bx: Box<[(K, V)]>) -> Self {
-                return Self::from_vec_unchecked(bx.into_vec())
-            }
+    #[inline]
+    pub fn get_index (&self, idx: usize) -> Option<(&K, &V)> {
+        let (k, v) = self.inner.as_slice().get(idx)?;
+        return Some((k, v))
+    }
 
-            #[inline]
-            pub fn into_vec (self) -> Vec<(K, V)> {
-                return self.inner
-            }
-        
-            #[inline]
-            pub fn into_box (self) -> Box<[(K, V)]> {
-                return self.inner.into_boxed_slice()
-            }
-        
-            #[inline]
-            pub fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V> where K: Eq {
-                for i in 0..self.inner.len() {
-                    if unsafe { &self.inner.get_unchecked(i).0 } == &key {
-                        return Entry::Occupied(OccupiedEntry {
-                            parent: &mut self.inner,
-                            idx: i,
-                        });
-                    }
-                }
-                return Entry::Vacant(VacantEntry {
-                    parent: &mut self.inner,
-                    key,
-                });
-            }
-        
-            #[inline]
-            pub fn into_keys (self) -> IntoKeys<K, V> {
-                return IntoKeys(self.inner.into_iter())
-            }
-        
-            #[inline]
-            pub fn into_values (self) -> IntoValues<K, V> {
-                return IntoValues(self.inner.into_iter())
-            }
-        
-            #[inline]
-            pub fn drain (&mut self) -> Drain<'_, K, V> {
-                return self.inner.drain(..)
-            }
-        }
-        
-        impl<K, V> IntoIterator for VecMap<K, V> {
-            type Item = (K, V);
-            type IntoIter = IntoIter<K, V>;
-        
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                self.inner.into_iter()
-            }
-        }
-        
-        impl<'a, K, V> IntoIterator for &'a VecMap<K, V> {
-            type Item = (&'a K, &'a V);
-            type IntoIter = Iter<'a, K, V>;
-        
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                VecMap::iter(self)
-            }
-        }
-        
-        impl<'a, K, V> IntoIterator for &'a mut VecMap<K, V> {
-            type Item = (&'a K, &'a mut V);
-            type IntoIter = IterMut<'a, K, V>;
-        
-            #[inline]
-            fn into_iter(self) -> Self::IntoIter {
-                VecMap::iter_mut(self)
-            }
-        }
-        
-        impl<K: Eq, V> Extend<(K, V)> for VecMap<K, V> {
-            #[inline]
-            fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
-                for (key, value) in iter {
-                    let _ = self.insert(key, value);
-                }
-            }
-        }
-        
-        impl<K: Eq, V> FromIterator<(K, V)> for VecMap<K, V> {
-            #[inline]
-            fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
-                let iter = iter.into_iter();
-                let mut result = Self::with_capacity(match iter.size_hint() {
-                    (_, Some(x)) => x,
-                    (x, _) => x
-                });
-        
-                result.extend(iter);
-                return result
-            }
-        }
-        
-        impl<K, V> Default for VecMap<K, V> {
-            #[inline]
-            fn default() -> Self {
-                Self { inner: Default::default() }
-            }
-        }
+    #[inline]
+    pub fn get_index_mut (&mut self, idx: usize) -> Option<(&K, &mut V)> {
+        let (k, v) = self.inner.as_mut_slice().get_mut(idx)?;
+        return Some((k, v))
+    }
 
-        impl<K, V> From<BoxMap<K, V>> for VecMap<K, V> {
-            #[inline]
-            fn from(value: BoxMap<K, V>) -> Self {
-                Self { inner: value.into_vec() }
-            }
-        }
+    #[inline]
+    pub fn swap_remove_index (&mut self, idx: usize) -> Option<(K, V)> {
+        if idx >= self.inner.len() { return None }
+        return Some(self.inner.swap_remove(idx))
+    }
 
-        impl<K: Debug, V: Debug> Debug for VecMap<K, V> {
-            #[inline]
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                f.debug_map().entries(self.iter()).finish()
-            }
-        }
-        
-        impl<Q: ?Sized + Eq, K: Borrow<Q>, V> Index<&Q> for VecMap<K, V> {
-            type Output = V;
-        
-            #[inline]
-            fn index(&self, index: &Q) -> &Self::Output {
-                self.get(index).expect("index not found")
-            }
-        }
-        
-        impl<Q: ?Sized + Eq, K: Borrow<Q>, V> IndexMut<&Q> for VecMap<K, V> {
-            #[inline]
-            fn index_mut (&mut self, index: &Q) -> &mut Self::Output {
-                self.get_mut(index).expect("index not found")
-            }
-        }
+    #[inline]
+    pub fn shift_remove_index (&mut self, idx: usize) -> Option<(K, V)> {
+        if idx >= self.inner.len() { return None }
+        return Some(self.inner.remove(idx))
     }
-}
 
-#[derive(Debug, Clone)]
-#[repr(transparent)]
-pub struct Keys<'a, K: 'a, V: 'a> (pub(super) core::slice::Iter<'a, (K, V)>);
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap_indices (&mut self, a: usize, b: usize) {
+        self.inner.as_mut_slice().swap(a, b)
+    }
 
-impl<'a, K, V> Iterator for Keys<'a, K, V> {
-    type Item = &'a K;
+    /// Moves the entry at position `from` to position `to`, shifting the entries in between.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` are out of bounds.
+    #[inline]
+    pub fn move_index (&mut self, from: usize, to: usize) {
+        let entries = self.inner.as_mut_slice();
+        if from < to {
+            entries[from..=to].rotate_left(1)
+        } else if to < from {
+            entries[to..=from].rotate_right(1)
+        }
+    }
 
+    /// Returns the index of the first entry whose key matches the predicate.
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let (key, _) = self.0.next()?;
-        return Some(key)
+    pub fn position_by<F: FnMut(&K) -> bool> (&self, mut f: F) -> Option<usize> {
+        return self.inner.as_slice().iter().position(|(k, _)| f(k))
     }
 
+    /// Returns the first entry whose key matches the predicate, along with its index.
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, _) = self.0.nth(n)?;
-        return Some(key)
+    pub fn find_by<F: FnMut(&K) -> bool> (&self, f: F) -> Option<(usize, &K, &V)> {
+        let idx = self.position_by(f)?;
+        let (k, v) = unsafe { self.inner.as_slice().get_unchecked(idx) };
+        return Some((idx, k, v))
     }
 
+    /// Returns the first entry whose key matches the predicate, along with its index.
     #[inline]
-    fn count(self) -> usize where Self: Sized, {
-        self.0.count()
+    pub fn find_by_mut<F: FnMut(&K) -> bool> (&mut self, f: F) -> Option<(usize, &K, &mut V)> {
+        let idx = self.position_by(f)?;
+        let (k, v) = unsafe { self.inner.as_mut_slice().get_unchecked_mut(idx) };
+        return Some((idx, k, v))
     }
 
     #[inline]
-    fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (key, _) = self.0.last()?;
-        return Some(key)
+    pub fn sort_keys (&mut self) where K: Ord {
+        self.inner.as_mut_slice().sort_unstable_by(|(x, _), (y, _)| x.cmp(y))
     }
 
+    #[docfg(feature = "alloc")]
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    pub fn sort_by_values (&mut self) where V: Ord {
+        self.inner.as_mut_slice().sort_by(|(_, x), (_, y)| x.cmp(y))
     }
-}
 
-impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    #[docfg(feature = "alloc")]
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let (key, _) = self.0.next_back()?;
-        return Some(key)
+    pub fn sort_by<F: FnMut((&K, &V), (&K, &V)) -> Ordering> (&mut self, mut cmp: F) {
+        self.inner.as_mut_slice().sort_by(|(k1, v1), (k2, v2)| cmp((k1, v1), (k2, v2)))
     }
 
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, _) = self.0.nth_back(n)?;
-        return Some(key)
+    pub fn sort_unstable_by<F: FnMut((&K, &V), (&K, &V)) -> Ordering> (&mut self, mut cmp: F) {
+        self.inner.as_mut_slice().sort_unstable_by(|(k1, v1), (k2, v2)| cmp((k1, v1), (k2, v2)))
     }
-}
 
-impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {
     #[inline]
-    fn len(&self) -> usize {
-        self.0.len()
+    pub fn sort_unstable_by_key<T: Ord, F: FnMut(&K, &V) -> T> (&mut self, mut f: F) {
+        self.inner.as_mut_slice().sort_unstable_by_key(|(k, v)| f(k, v))
     }
-}
 
-#[derive(Debug, Clone)]
-#[repr(transparent)]
-pub struct Values<'a, K: 'a, V: 'a> (pub(super) core::slice::Iter<'a, (K, V)>);
+    /// Moves the first `n` entries (as ordered by `cmp`) to the front of the map, sorted,
+    /// and returns an iterator over them. The order of the remaining entries is unspecified.
+    ///
+    /// Only the selected entries are sorted, so this is O(len + n log n).
+    #[docfg(feature = "alloc")]
+    #[inline]
+    pub fn top_k_by<F: FnMut((&K, &V), (&K, &V)) -> Ordering> (&mut self, n: usize, mut cmp: F) -> Iter<'_, K, V> {
+        let entries = self.inner.as_mut_slice();
+        let n = usize::min(n, entries.len());
+        if n < entries.len() {
+            entries.select_nth_unstable_by(n, |(k1, v1), (k2, v2)| cmp((k1, v1), (k2, v2)));
+        }
 
-impl<'a, K, V> Iterator for Values<'a, K, V> {
-    type Item = &'a V;
+        let top = &mut entries[..n];
+        top.sort_by(|(k1, v1), (k2, v2)| cmp((k1, v1), (k2, v2)));
+        return Iter(top.iter())
+    }
 
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let (_, values) = self.0.next()?;
-        return Some(values)
+    fn is_sorted_by_key (&self) -> bool where K: Ord {
+        return self.inner.as_slice().windows(2).all(|x| x[0].0 < x[1].0)
     }
 
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (_, values) = self.0.nth(n)?;
-        return Some(values)
+    pub fn iter (&self) -> Iter<'_, K, V> {
+        return Iter(self.inner.as_slice().iter())
     }
 
     #[inline]
-    fn count(self) -> usize where Self: Sized, {
-        self.0.count()
+    pub fn iter_mut (&mut self) -> IterMut<'_, K, V> {
+        return IterMut(self.inner.as_mut_slice().iter_mut())
     }
 
     #[inline]
-    fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (_, values) = self.0.last()?;
-        return Some(values)
+    pub fn keys (&self) -> Keys<'_, K, V> {
+        return Keys(self.inner.as_slice().iter())
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    pub fn values (&self) -> Values<'_, K, V> {
+        return Values(self.inner.as_slice().iter())
     }
-}
 
-impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let (_, values) = self.0.next_back()?;
-        return Some(values)
+    pub fn values_mut (&mut self) -> ValuesMut<'_, K, V> {
+        return ValuesMut(self.inner.as_mut_slice().iter_mut())
     }
 
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (_, values) = self.0.nth_back(n)?;
-        return Some(values)
+    pub fn into_keys (self) -> IntoKeys<K, V, S> where S: IntoIterator<Item = (K, V)> {
+        return IntoKeys(self.inner.into_iter())
     }
-}
 
-impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {
     #[inline]
-    fn len(&self) -> usize {
-        self.0.len()
+    pub fn into_values (self) -> IntoValues<K, V, S> where S: IntoIterator<Item = (K, V)> {
+        return IntoValues(self.inner.into_iter())
     }
-}
 
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct ValuesMut<'a, K: 'a, V: 'a> (pub(super) core::slice::IterMut<'a, (K, V)>);
+    #[inline]
+    pub fn extract_if<F: FnMut(&K, &mut V) -> bool> (&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self> {
+        return ExtractIf::new(self, pred)
+    }
+}
 
-impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
-    type Item = &'a mut V;
+#[docfg(feature = "alloc")]
+impl<K, V, S: MapStorage<K, V>> VecMap<K, V, S> {
+    /// Builds a map out of a list of entries, resolving duplicate keys with `policy`. Runs in O(n²), even if `K: Hash`,
+    /// since there is no hash set without `std`. Use [`from_vec_ord`](Self::from_vec_ord) for keys that are `Ord`.
+    #[inline]
+    pub fn from_vec (vec: S, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<S>> where K: Eq {
+        let duplicates = dedup::duplicates_eq(vec.as_slice());
+        return Self::from_vec_with(vec, duplicates, policy)
+    }
 
+    /// Same as [`from_vec`](Self::from_vec), but runs in O(n log n) by sorting the keys.
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let (_, values) = self.0.next()?;
-        return Some(values)
+    pub fn from_vec_ord (vec: S, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<S>> where K: Ord {
+        let duplicates = dedup::duplicates_ord(vec.as_slice());
+        return Self::from_vec_with(vec, duplicates, policy)
     }
 
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (_, values) = self.0.nth(n)?;
-        return Some(values)
+    fn from_vec_with (mut vec: S, duplicates: Vec<(usize, usize)>, policy: DuplicatePolicy) -> Result<Self, DuplicateKeysError<S>> {
+        return match dedup::resolve(vec.as_mut_slice(), duplicates, policy) {
+            Ok(len) => {
+                vec.truncate(len);
+                Ok(Self { inner: vec, _marker: PhantomData })
+            },
+            Err(indices) => Err(DuplicateKeysError::new(indices, vec))
+        }
     }
+}
 
+#[docfg(feature = "alloc")]
+impl<K, V, S: HeapStorage<K, V>, P: OrderPolicy> VecMap<K, V, S, P> {
     #[inline]
-    fn count(self) -> usize where Self: Sized, {
-        self.0.count()
+    pub fn into_box (self) -> S::Boxed {
+        return self.inner.into_boxed()
     }
 
     #[inline]
-    fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (_, values) = self.0.last()?;
-        return Some(values)
+    pub fn capacity (&self) -> usize {
+        return self.inner.capacity()
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    pub fn reserve (&mut self, additional: usize) {
+        self.inner.reserve(additional)
     }
-}
 
-impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let (_, values) = self.0.next_back()?;
-        return Some(values)
+    pub fn reserve_exact (&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
     }
 
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (_, values) = self.0.nth_back(n)?;
-        return Some(values)
+    pub fn try_reserve (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        return self.inner.try_reserve(additional)
     }
-}
 
-impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {
     #[inline]
-    fn len(&self) -> usize {
-        self.0.len()
+    pub fn try_reserve_exact (&mut self, additional: usize) -> Result<(), TryReserveError> {
+        return self.inner.try_reserve_exact(additional)
     }
-}
 
-#[derive(Debug, Clone)]
-#[repr(transparent)]
-pub struct IntoKeys<K, V, #[cfg(feature = "alloc")] A: Allocator = Global> (
-    #[cfg(feature = "alloc")]
-    pub(super) alloc::vec::IntoIter<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    pub(super) alloc::vec::IntoIter<(K, V)>,
-);
-
-impl<K, V> Iterator for IntoKeys<K, V> {
-    type Item = K;
+    #[inline]
+    pub fn shrink_to (&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity)
+    }
 
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let (key, _) = self.0.next()?;
-        return Some(key)
+    pub fn shrink_to_fit (&mut self) {
+        self.inner.shrink_to_fit()
     }
 
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, _) = self.0.nth(n)?;
-        return Some(key)
+    pub fn insert (&mut self, key: K, value: V) -> Option<V> where K: Eq {
+        return match self.entry(key) {
+            Entry::Occupied(mut x) => Some(x.insert(value)),
+            Entry::Vacant(x) => {
+                x.insert(value);
+                None
+            }
+        }
     }
 
+    /// Same as [`insert`](Self::insert), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entry, handing the entry back alongside it.
     #[inline]
-    fn count(self) -> usize where Self: Sized, {
-        self.0.count()
+    pub fn try_insert_alloc (&mut self, key: K, value: V) -> Result<Option<V>, (TryReserveError, K, V)> where K: Eq {
+        if let Some(idx) = self.get_index_of(&key) {
+            let entry = unsafe { self.inner.as_mut_slice().get_unchecked_mut(idx) };
+            return Ok(Some(core::mem::replace(&mut entry.1, value)))
+        }
+
+        if let Err(e) = self.inner.try_reserve(1) {
+            return Err((e, key, value))
+        }
+        let len = self.inner.len();
+        self.inner.insert_growing(len, (key, value));
+        return Ok(None)
     }
 
+    /// Same as [`extend`](Extend::extend), but returns an error instead of aborting if the map
+    /// fails to allocate room for the new entries. The entries inserted before the failure are kept.
     #[inline]
-    fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (key, _) = self.0.last()?;
-        return Some(key)
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>> (&mut self, iter: I) -> Result<(), TryReserveError> where K: Eq {
+        let iter = iter.into_iter();
+        self.inner.try_reserve(iter.size_hint().0)?;
+        for (key, value) in iter {
+            self.try_insert_alloc(key, value).map_err(|(e, _, _)| e)?;
+        }
+        return Ok(())
+    }
+
+    /// Inserts the entry at position `idx`, shifting all the following entries.
+    /// If the key was already present, its value is replaced and the entry is moved to `idx`.
+    /// In both cases, an `idx` of `self.len()` places the entry last.
+    ///
+    /// # Panics
+    /// Panics if `idx > self.len()`, before modifying the map.
+    #[inline]
+    pub fn insert_at (&mut self, idx: usize, key: K, value: V) -> Option<V> where K: Eq {
+        let len = self.inner.len();
+        assert!(idx <= len, "insertion index (is {idx}) should be <= len (is {len})");
+
+        return match self.get_index_of(&key) {
+            Some(prev) => {
+                let value = core::mem::replace(&mut self.inner.as_mut_slice()[prev].1, value);
+                self.move_index(prev, usize::min(idx, len - 1));
+                Some(value)
+            },
+            None => {
+                self.inner.insert_growing(idx, (key, value));
+                None
+            }
+        }
     }
 
+    /// Inserts the entry if the key isn't already present, returning a reference to the new value.
+    /// Otherwise, the map is left untouched, and an error holding the existing entry and the rejected value is returned.
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    pub fn try_insert (&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<OccupiedEntry<'_, K, V, S, P>>> where K: Eq {
+        return match self.entry(key) {
+            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+            Entry::Vacant(entry) => Ok(entry.insert(value))
+        }
     }
-}
 
-impl<K, V> DoubleEndedIterator for IntoKeys<K, V> {
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let (key, _) = self.0.next_back()?;
-        return Some(key)
+    pub fn drain (&mut self) -> S::Drain<'_> {
+        return self.inner.drain(..)
     }
 
+    /// Splits the map in two, with the entries that match the predicate on the first one,
+    /// and the ones that don't on the second. Both maps keep the entries' relative order.
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, _) = self.0.nth_back(n)?;
-        return Some(key)
+    pub fn partition<F: FnMut(&K, &mut V) -> bool> (mut self, mut pred: F) -> (Self, Self) where S::Alloc: Clone {
+        let mut rhs = S::with_capacity_in(0, self.inner.allocator().clone());
+        for entry in self.extract_if(|k, v| !pred(k, v)) {
+            let len = rhs.len();
+            rhs.insert_growing(len, entry);
+        }
+        return (self, Self { inner: rhs, _marker: PhantomData })
     }
 }
 
-impl<K, V> ExactSizeIterator for IntoKeys<K, V> {
+impl<K, V, S: MapStorage<K, V> + IntoIterator<Item = (K, V)>, P: OrderPolicy> IntoIterator for VecMap<K, V, S, P> {
+    type Item = (K, V);
+    type IntoIter = S::IntoIter;
+
     #[inline]
-    fn len(&self) -> usize {
-        self.0.len()
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
     }
 }
 
-#[derive(Debug, Clone)]
-#[repr(transparent)]
-pub struct IntoValues<K, V, #[cfg(feature = "alloc")] A: Allocator = Global> (
-    #[cfg(feature = "alloc")]
-    pub(super) alloc::vec::IntoIter<(K, V), A>,
-    #[cfg(not(feature = "alloc"))]
-    pub(super) alloc::vec::IntoIter<(K, V)>,
-);
-
-impl<K, V> Iterator for IntoValues<K, V> {
-    type Item = V;
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy> IntoIterator for &'a VecMap<K, V, S, P> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
 
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let (_, values) = self.0.next()?;
-        return Some(values)
+    fn into_iter(self) -> Self::IntoIter {
+        VecMap::iter(self)
     }
+}
+
+impl<'a, K, V, S: MapStorage<K, V>, P: OrderPolicy> IntoIterator for &'a mut VecMap<K, V, S, P> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
 
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (_, values) = self.0.nth(n)?;
-        return Some(values)
+    fn into_iter(self) -> Self::IntoIter {
+        VecMap::iter_mut(self)
     }
+}
 
+#[docfg(feature = "alloc")]
+impl<K: Eq, V, S: HeapStorage<K, V>, P: OrderPolicy> Extend<(K, V)> for VecMap<K, V, S, P> {
     #[inline]
-    fn count(self) -> usize where Self: Sized, {
-        self.0.count()
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            let _ = self.insert(key, value);
+        }
     }
+}
 
-    #[inline]
-    fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (_, values) = self.0.last()?;
-        return Some(values)
+#[docfg(feature = "alloc")]
+impl<K: Eq, V, S: HeapStorage<K, V>, P: OrderPolicy> FromIterator<(K, V)> for VecMap<K, V, S, P> where S::Alloc: Default {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, V)>> (iter: T) -> Self {
+        let iter = iter.into_iter();
+        let mut result = Self {
+            inner: S::with_capacity_in(match iter.size_hint() {
+                (_, Some(x)) => x,
+                (x, _) => x
+            }, Default::default()),
+            _marker: PhantomData,
+        };
+
+        result.extend(iter);
+        return result
     }
+}
 
+impl<K, V, S: MapStorage<K, V> + Default, P: OrderPolicy> Default for VecMap<K, V, S, P> {
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    fn default() -> Self {
+        Self { inner: Default::default(), _marker: PhantomData }
     }
 }
 
-impl<K, V> DoubleEndedIterator for IntoValues<K, V> {
+impl<K: Debug, V: Debug, S: MapStorage<K, V>, P: OrderPolicy> Debug for VecMap<K, V, S, P> {
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let (_, values) = self.0.next_back()?;
-        return Some(values)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
     }
+}
+
+impl<Q: ?Sized + Eq, K: Borrow<Q>, V, S: MapStorage<K, V>, P: OrderPolicy> Index<&Q> for VecMap<K, V, S, P> {
+    type Output = V;
 
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (_, values) = self.0.nth_back(n)?;
-        return Some(values)
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).expect("index not found")
     }
 }
 
-impl<K, V> ExactSizeIterator for IntoValues<K, V> {
+impl<Q: ?Sized + Eq, K: Borrow<Q>, V, S: MapStorage<K, V>, P: OrderPolicy> IndexMut<&Q> for VecMap<K, V, S, P> {
     #[inline]
-    fn len(&self) -> usize {
-        self.0.len()
+    fn index_mut (&mut self, index: &Q) -> &mut Self::Output {
+        self.get_mut(index).expect("index not found")
     }
 }
 
-#[derive(Debug, Clone)]
+/// Implements the conversions between a `Vec`-backed [`VecMap`] and the types of the `alloc` crate.
+#[cfg(feature = "alloc")]
+macro_rules! impl_vec_conversions {
+    (impl[$($g:tt)*] $vec:ty, $boxed:ty => $boxmap:ty) => {
+        impl<K, V, P: OrderPolicy, $($g)*> VecMap<K, V, $vec, P> {
+            /// # Safety
+            /// The keys must be unique.
+            #[inline]
+            pub unsafe fn from_box_unchecked (bx: $boxed) -> Self {
+                return Self::from_vec_unchecked(bx.into_vec())
+            }
+        }
+
+        /// Fails if any key appears more than once, listing the indices of the duplicates.
+        ///
+        /// Since it only requires `K: Eq`, the keys are compared pairwise in O(n²). For keys that are `Ord`,
+        /// [`VecMap::from_vec_ord`] with [`DuplicatePolicy::Error`] does the same in O(n log n).
+        impl<K: Eq, V, P: OrderPolicy, $($g)*> TryFrom<$vec> for VecMap<K, V, $vec, P> {
+            type Error = DuplicateKeysError<$vec>;
+
+            #[inline]
+            fn try_from(value: $vec) -> Result<Self, Self::Error> {
+                VecMap::from_vec(value, DuplicatePolicy::Error).map(VecMap::into_policy)
+            }
+        }
+
+        impl<K, V, P: OrderPolicy, $($g)*> From<$boxmap> for VecMap<K, V, $vec, P> {
+            #[inline]
+            fn from(value: $boxmap) -> Self {
+                Self { inner: value.into_vec(), _marker: PhantomData }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "allocator_api")]
+impl_vec_conversions!(impl[A: Allocator] Vec<(K, V), A>, crate::heap::boxed::Box<[(K, V)], A> => crate::r#box::BoxMap<K, V, A>);
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl_vec_conversions!(impl[] Vec<(K, V)>, crate::heap::boxed::Box<[(K, V)]> => crate::r#box::BoxMap<K, V>);
+
 #[repr(transparent)]
-pub struct Iter<'a, K: 'a, V: 'a> (pub(super) core::slice::Iter<'a, (K, V)>);
+pub struct IntoKeys<K, V, S: IntoIterator<Item = (K, V)>> (pub(super) S::IntoIter);
 
-impl<'a, K, V> Iterator for Iter<'a, K, V> {
-    type Item = (&'a K, &'a V);
+impl<K, V, S: IntoIterator<Item = (K, V)>> Iterator for IntoKeys<K, V, S> {
+    type Item = K;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let (key, value) = self.0.next()?;
-        return Some((key, value))
+        let (key, _) = self.0.next()?;
+        return Some(key)
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, value) = self.0.nth(n)?;
-        return Some((key, value))
+        let (key, _) = self.0.nth(n)?;
+        return Some(key)
     }
 
     #[inline]
@@ -800,8 +772,8 @@ impl<'a, K, V> Iterator for Iter<'a, K, V> {
 
     #[inline]
     fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (key, value) = self.0.last()?;
-        return Some((key, value))
+        let (key, _) = self.0.last()?;
+        return Some(key)
     }
 
     #[inline]
@@ -810,44 +782,57 @@ impl<'a, K, V> Iterator for Iter<'a, K, V> {
     }
 }
 
-impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+impl<K, V, S: IntoIterator<Item = (K, V)>> DoubleEndedIterator for IntoKeys<K, V, S> where S::IntoIter: DoubleEndedIterator {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        let (key, value) = self.0.next_back()?;
-        return Some((key, value))
+        let (key, _) = self.0.next_back()?;
+        return Some(key)
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, value) = self.0.nth_back(n)?;
-        return Some((key, value))
+        let (key, _) = self.0.nth_back(n)?;
+        return Some(key)
     }
 }
 
-impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
+impl<K, V, S: IntoIterator<Item = (K, V)>> ExactSizeIterator for IntoKeys<K, V, S> where S::IntoIter: ExactSizeIterator {
     #[inline]
     fn len(&self) -> usize {
         self.0.len()
     }
 }
 
-#[derive(Debug)]
+impl<K, V, S: IntoIterator<Item = (K, V)>> Debug for IntoKeys<K, V, S> where S::IntoIter: Debug {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoKeys").field(&self.0).finish()
+    }
+}
+
+impl<K, V, S: IntoIterator<Item = (K, V)>> Clone for IntoKeys<K, V, S> where S::IntoIter: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 #[repr(transparent)]
-pub struct IterMut<'a, K: 'a, V: 'a> (pub(super) core::slice::IterMut<'a, (K, V)>);
+pub struct IntoValues<K, V, S: IntoIterator<Item = (K, V)>> (pub(super) S::IntoIter);
 
-impl<'a, K, V> Iterator for IterMut<'a, K, V> {
-    type Item = (&'a K, &'a mut V);
+impl<K, V, S: IntoIterator<Item = (K, V)>> Iterator for IntoValues<K, V, S> {
+    type Item = V;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let (key, value) = self.0.next()?;
-        return Some((key, value))
+        let (_, values) = self.0.next()?;
+        return Some(values)
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, value) = self.0.nth(n)?;
-        return Some((key, value))
+        let (_, values) = self.0.nth(n)?;
+        return Some(values)
     }
 
     #[inline]
@@ -857,8 +842,8 @@ impl<'a, K, V> Iterator for IterMut<'a, K, V> {
 
     #[inline]
     fn last(self) -> Option<Self::Item> where Self: Sized, {
-        let (key, value) = self.0.last()?;
-        return Some((key, value))
+        let (_, values) = self.0.last()?;
+        return Some(values)
     }
 
     #[inline]
@@ -867,23 +852,58 @@ impl<'a, K, V> Iterator for IterMut<'a, K, V> {
     }
 }
 
-impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+impl<K, V, S: IntoIterator<Item = (K, V)>> DoubleEndedIterator for IntoValues<K, V, S> where S::IntoIter: DoubleEndedIterator {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        let (key, value) = self.0.next_back()?;
-        return Some((key, value))
+        let (_, values) = self.0.next_back()?;
+        return Some(values)
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (key, value) = self.0.nth_back(n)?;
-        return Some((key, value))
+        let (_, values) = self.0.nth_back(n)?;
+        return Some(values)
     }
 }
 
-impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {
+impl<K, V, S: IntoIterator<Item = (K, V)>> ExactSizeIterator for IntoValues<K, V, S> where S::IntoIter: ExactSizeIterator {
     #[inline]
     fn len(&self) -> usize {
         self.0.len()
     }
-}
\ No newline at end of file
+}
+
+impl<K, V, S: IntoIterator<Item = (K, V)>> Debug for IntoValues<K, V, S> where S::IntoIter: Debug {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoValues").field(&self.0).finish()
+    }
+}
+
+impl<K, V, S: IntoIterator<Item = (K, V)>> Clone for IntoValues<K, V, S> where S::IntoIter: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Builds a [`VecMap`] out of literal entries, allocating exactly once. Duplicate keys panic in debug builds,
+/// and keep their last value otherwise. An `in alloc;` prefix builds the map in the given allocator.
+///
+/// ```
+/// let map = vector_mapp::vecmap! { "one" => 1, "two" => 2 };
+/// assert_eq!(map.get("two"), Some(&2));
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! vecmap {
+    ($(in $alloc:expr;)? $($key:expr => $value:expr),* $(,)?) => {
+        match $crate::vec::VecMap::from_vec(
+            $crate::__literal_entries!($(in $alloc;)? $($key => $value),*),
+            $crate::__private::literal_policy(cfg!(debug_assertions)),
+        ) {
+            Ok(map) => map,
+            Err(err) => panic!("vecmap!: {}", err),
+        }
+    };
+}
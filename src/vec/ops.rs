@@ -0,0 +1,176 @@
+use super::{OrderPolicy, VecMap};
+use crate::search::SearchKey;
+use crate::storage::MapStorage;
+#[cfg(feature = "alloc")]
+use crate::storage::HeapStorage;
+use core::{iter::{Chain, FusedIterator}, slice};
+#[cfg(feature = "alloc")]
+use core::{marker::PhantomData, ops::{BitAnd, BitOr, BitXor, Sub}};
+
+#[inline]
+fn contains<K: Eq, V> (entries: &[(K, V)], key: &K) -> bool {
+    return K::position(entries, key, |(k, _)| k).is_some()
+}
+
+/// A lazy iterator over the entries of the first map whose key is also in the second one,
+/// created by [`VecMap::intersection`].
+#[derive(Debug, Clone)]
+pub struct Intersection<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+    other: &'a [(K, V)],
+}
+
+impl<'a, K: Eq, V> Iterator for Intersection<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        let (k, v) = self.iter.find(|(k, _)| contains(other, k))?;
+        return Some((k, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (0, Some(usize::min(self.iter.len(), self.other.len())))
+    }
+}
+
+/// A lazy iterator over the entries of the first map whose key isn't in the second one,
+/// created by [`VecMap::difference`].
+#[derive(Debug, Clone)]
+pub struct Difference<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+    other: &'a [(K, V)],
+}
+
+impl<'a, K: Eq, V> Iterator for Difference<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        let (k, v) = self.iter.find(|(k, _)| !contains(other, k))?;
+        return Some((k, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.iter.len().saturating_sub(self.other.len()), Some(self.iter.len()))
+    }
+}
+
+/// A lazy iterator over the entries of either map, created by [`VecMap::union`].
+#[derive(Debug, Clone)]
+pub struct Union<'a, K, V> (Chain<super::Iter<'a, K, V>, Difference<'a, K, V>>);
+
+impl<'a, K: Eq, V> Iterator for Union<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// A lazy iterator over the entries whose key is in exactly one of the maps,
+/// created by [`VecMap::symmetric_difference`].
+#[derive(Debug, Clone)]
+pub struct SymmetricDifference<'a, K, V> (Chain<Difference<'a, K, V>, Difference<'a, K, V>>);
+
+impl<'a, K: Eq, V> Iterator for SymmetricDifference<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K: Eq, V> FusedIterator for Union<'_, K, V> {}
+impl<K: Eq, V> FusedIterator for Intersection<'_, K, V> {}
+impl<K: Eq, V> FusedIterator for Difference<'_, K, V> {}
+impl<K: Eq, V> FusedIterator for SymmetricDifference<'_, K, V> {}
+
+impl<K: Eq, V, S: MapStorage<K, V>, P: OrderPolicy> VecMap<K, V, S, P> {
+    /// Iterates the entries of `self`, followed by the entries of `other` whose key isn't in `self`, in O(n * m).
+    /// [`BinaryMap::union`](crate::binary::BinaryMap::union) does the same in linear time.
+    #[inline]
+    pub fn union<'a> (&'a self, other: &'a Self) -> Union<'a, K, V> {
+        return Union(self.iter().chain(other.difference(self)))
+    }
+
+    /// Iterates the entries of `self` whose key is also in `other`, in O(n * m).
+    #[inline]
+    pub fn intersection<'a> (&'a self, other: &'a Self) -> Intersection<'a, K, V> {
+        return Intersection { iter: self.inner.as_slice().iter(), other: other.inner.as_slice() }
+    }
+
+    /// Iterates the entries of `self` whose key isn't in `other`, in O(n * m).
+    #[inline]
+    pub fn difference<'a> (&'a self, other: &'a Self) -> Difference<'a, K, V> {
+        return Difference { iter: self.inner.as_slice().iter(), other: other.inner.as_slice() }
+    }
+
+    /// Iterates the entries of `self` whose key isn't in `other`, followed by the entries of `other`
+    /// whose key isn't in `self`, in O(n * m).
+    #[inline]
+    pub fn symmetric_difference<'a> (&'a self, other: &'a Self) -> SymmetricDifference<'a, K, V> {
+        return SymmetricDifference(self.difference(other).chain(other.difference(self)))
+    }
+
+    /// Returns `true` if every key of `self` is also in `other`. Values are not compared.
+    #[inline]
+    pub fn is_subset (&self, other: &Self) -> bool {
+        return self.len() <= other.len() && self.difference(other).next().is_none()
+    }
+
+    /// Returns `true` if every key of `other` is also in `self`. Values are not compared.
+    #[inline]
+    pub fn is_superset (&self, other: &Self) -> bool {
+        return other.is_subset(self)
+    }
+
+    /// Returns `true` if the maps have no keys in common.
+    #[inline]
+    pub fn is_disjoint (&self, other: &Self) -> bool {
+        return self.intersection(other).next().is_none()
+    }
+}
+
+macro_rules! impl_ops {
+    ($($trait:ident :: $fn:ident => $iter:ident),+ $(,)?) => {
+        $(
+            #[cfg(feature = "alloc")]
+            impl<K: Eq + Clone, V: Clone, S: HeapStorage<K, V>, P: OrderPolicy> $trait<&VecMap<K, V, S, P>> for &VecMap<K, V, S, P> where S::Alloc: Clone {
+                type Output = VecMap<K, V, S, P>;
+
+                #[inline]
+                fn $fn (self, rhs: &VecMap<K, V, S, P>) -> Self::Output {
+                    let mut inner = S::with_capacity_in(0, self.inner.allocator().clone());
+                    for (k, v) in self.$iter(rhs) {
+                        inner.insert_growing(inner.len(), (k.clone(), v.clone()));
+                    }
+                    return VecMap { inner, _marker: PhantomData }
+                }
+            }
+        )+
+    };
+}
+
+impl_ops! {
+    BitOr::bitor => union,
+    BitAnd::bitand => intersection,
+    Sub::sub => difference,
+    BitXor::bitxor => symmetric_difference,
+}
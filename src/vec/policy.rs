@@ -0,0 +1,59 @@
+/// Decides how a [`VecMap`](super::VecMap) fills the gap left by a removed entry.
+pub trait OrderPolicy: Copy {
+    /// If `true`, removals shift the following entries, preserving insertion order.
+    /// Otherwise, the last entry is swapped into the removed slot.
+    const PRESERVE_ORDER: bool;
+}
+
+/// Removals are O(1), but may reorder the remaining entries (the default).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unordered;
+
+/// Removals are O(n), but always preserve insertion order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ordered;
+
+impl OrderPolicy for Unordered {
+    const PRESERVE_ORDER: bool = false;
+}
+
+impl OrderPolicy for Ordered {
+    const PRESERVE_ORDER: bool = true;
+}
+
+/// Decides how far a [`SelfOrganizingVecMap`](super::SelfOrganizingVecMap) moves an entry towards the front
+/// every time it's promoted. Its removals preserve the order the promotions built up.
+pub trait PromotionPolicy: OrderPolicy {
+    /// Returns the position the entry currently at `idx` is moved to.
+    fn target (idx: usize) -> usize;
+}
+
+/// Promoted entries are moved straight to the front of the map (the default).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MoveToFront;
+
+/// Promoted entries are swapped with the entry right before them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Transpose;
+
+impl OrderPolicy for MoveToFront {
+    const PRESERVE_ORDER: bool = true;
+}
+
+impl OrderPolicy for Transpose {
+    const PRESERVE_ORDER: bool = true;
+}
+
+impl PromotionPolicy for MoveToFront {
+    #[inline]
+    fn target (_idx: usize) -> usize {
+        return 0
+    }
+}
+
+impl PromotionPolicy for Transpose {
+    #[inline]
+    fn target (idx: usize) -> usize {
+        return idx.saturating_sub(1)
+    }
+}
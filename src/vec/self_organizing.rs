@@ -0,0 +1,41 @@
+use super::*;
+
+/// A [`VecMap`] that moves entries towards the front of its buffer every time they're looked up
+/// through [`get_promote`](VecMap::get_promote), so that frequently accessed keys are found earlier
+/// by the linear scan.
+///
+/// How far an entry is moved is decided by the [`PromotionPolicy`], which takes the place of the
+/// [`OrderPolicy`] of the map. Removals always preserve the order of the remaining entries.
+pub type SelfOrganizingVecMap<K, V, #[cfg(feature = "alloc")] S = Vec<(K, V)>, #[cfg(not(feature = "alloc"))] S, P = MoveToFront> = VecMap<K, V, S, P>;
+
+impl<K, V, S: MapStorage<K, V>, P: PromotionPolicy> VecMap<K, V, S, P> {
+    /// Looks up `key`, and moves its entry towards the front of the map.
+    #[inline]
+    pub fn get_promote<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        let idx = self.promote(key)?;
+        return self.get_index(idx).map(|(_, v)| v)
+    }
+
+    /// Looks up `key`, and moves its entry towards the front of the map.
+    #[inline]
+    pub fn get_promote_mut<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let idx = self.promote(key)?;
+        return self.get_index_mut(idx).map(|(_, v)| v)
+    }
+
+    /// Looks up `key`, and moves its entry towards the front of the map.
+    #[inline]
+    pub fn get_key_value_promote<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        let idx = self.promote(key)?;
+        return self.get_index(idx)
+    }
+
+    /// Moves the entry of `key` towards the front of the map, returning its new position.
+    #[inline]
+    pub fn promote<Q: ?Sized + Eq> (&mut self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        let idx = self.get_index_of(key)?;
+        let target = P::target(idx);
+        self.move_index(idx, target);
+        return Some(target)
+    }
+}
@@ -0,0 +1,133 @@
+use std::{hash::{DefaultHasher, Hash, Hasher}, rc::Rc};
+use vector_mapp::{array::{ArrayBinaryMap, ArrayMap}, binary, vec::Entry};
+
+static EMPTY: ArrayMap<u8, u8, 4> = ArrayMap::new();
+
+#[test]
+fn map () {
+    assert!(EMPTY.is_empty());
+
+    let mut v = ArrayMap::<_, _, 3>::new();
+    assert_eq!(v.insert(1, 'a'), Ok(None));
+    assert_eq!(v.insert(2, 'b'), Ok(None));
+    assert_eq!(v.insert(1, 'c'), Ok(Some('a')));
+    assert_eq!(v.insert(3, 'd'), Ok(None));
+    assert!(v.is_full());
+    assert_eq!(v.insert(4, 'e'), Err((4, 'e')));
+    assert_eq!(v.insert(2, 'f'), Ok(Some('b')));
+
+    assert_eq!(v.remove(&1), Some('c'));
+    assert!(v.iter().eq([(&3, &'d'), (&2, &'f')]));
+    assert_eq!(v[&3], 'd');
+
+    match v.entry(5) {
+        Entry::Vacant(entry) => assert_eq!(entry.checked_insert('g'), Ok(&mut 'g')),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(v.entry(6).checked_or_insert('h'), Err((6, 'h')));
+    assert!(v.iter().eq([(&3, &'d'), (&2, &'f'), (&5, &'g')]));
+
+    match v.entry(3) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), 'd'),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(v.into_iter().eq([(5, 'g'), (2, 'f')]));
+}
+
+#[test]
+fn binary () {
+    let mut v = ArrayBinaryMap::<_, _, 4>::try_from([(3, 'c'), (1, 'a'), (2, 'b')]).unwrap();
+    assert!(v.keys().eq([1, 2, 3].iter()));
+    assert_eq!(v.insert(0, 'z'), Ok(None));
+    assert_eq!(v.insert(5, 'e'), Err((5, 'e')));
+
+    *v.entry(2).checked_or_insert('x').unwrap() = 'y';
+    assert_eq!(v.get(&2), Some(&'y'));
+    assert_eq!(v.remove(&1), Some('a'));
+    assert!(v.iter().eq([(&0, &'z'), (&2, &'y'), (&3, &'c')]));
+
+    match v.entry(0) {
+        binary::Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (0, 'z')),
+        binary::Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(v.keys().eq([2, 3].iter()));
+    v.insert(0, 'z').unwrap();
+
+    v.retain(|k, _| *k != 2);
+    assert!(v.into_iter().rev().eq([(3, 'c'), (0, 'z')]));
+    assert!(ArrayBinaryMap::<_, _, 1>::try_from([(1, 1), (2, 2)]).is_err());
+}
+
+#[test]
+fn drops () {
+    let value = Rc::new(());
+    let mut v = ArrayMap::<_, _, 4>::new();
+    for i in 0..4 {
+        v.insert(i, value.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), 5);
+
+    v.retain(|k, _| k % 2 == 0);
+    assert_eq!(Rc::strong_count(&value), 3);
+
+    let mut iter = v.clone().into_iter();
+    assert_eq!(Rc::strong_count(&value), 5);
+    drop(iter.next());
+    drop(iter);
+    drop(v);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn extend_and_compare () {
+    let mut v = ArrayMap::<_, _, 3>::new();
+    assert_eq!(v.checked_extend([(1, 'a'), (2, 'b')]), Ok(()));
+    assert_eq!(v.checked_extend([(1, 'c'), (3, 'd'), (4, 'e'), (5, 'f')]), Err((4, 'e')));
+    assert!(v.iter().eq([(&1, &'c'), (&2, &'b'), (&3, &'d')]));
+
+    let w = ArrayMap::<_, _, 3>::try_from([(1, 'c'), (2, 'b'), (3, 'd')]).unwrap();
+    assert_eq!(v, w);
+    assert_eq!(hash(&v), hash(&w));
+    assert!(v.clone().into_keys().eq([1, 2, 3]));
+    assert!(w.into_values().eq(['c', 'b', 'd']));
+
+    let mut b = ArrayBinaryMap::<_, _, 3>::new();
+    assert_eq!(b.checked_extend([(3, 'c'), (1, 'a')]), Ok(()));
+    assert_eq!(b.checked_extend([(2, 'b'), (0, 'z')]), Err((0, 'z')));
+    assert_eq!(b, ArrayBinaryMap::try_from([(2, 'b'), (1, 'a'), (3, 'c')]).unwrap());
+    assert!(b.into_keys().rev().eq([3, 2, 1]));
+}
+
+#[test]
+fn positional () {
+    let mut v = ArrayMap::<_, _, 6>::try_from([(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]).unwrap();
+    assert_eq!(v.get_many([&1, &3]), Some([&10, &30]));
+    assert_eq!(v.get_many([&1, &5]), None);
+    if let Some([x, y]) = v.get_many_mut([&0, &4]) {
+        std::mem::swap(x, y);
+    }
+    assert_eq!(v.get_many_mut([&2, &2]), None);
+
+    v.move_index(0, 2);
+    v.swap_indices(0, 4);
+    assert!(v.keys().eq([4, 2, 0, 3, 1].iter()));
+    assert_eq!(v.get_index(2), Some((&0, &40)));
+    assert_eq!(v.get_index_of(&3), Some(3));
+
+    let odd = v.extract_if(|k, _| k % 2 == 1).collect::<Vec<_>>();
+    assert_eq!(odd, [(3, 30), (1, 10)]);
+    assert!(v.iter().eq([(&4, &0), (&2, &20), (&0, &40)]));
+    assert!(!v.is_full());
+
+    let mut b = ArrayBinaryMap::<_, _, 4>::try_from([(3, 'c'), (1, 'a'), (2, 'b')]).unwrap();
+    assert_eq!(b.get_index(1), Some((&2, &'b')));
+    assert_eq!(b.get_many([&3, &1]), Some([&'c', &'a']));
+    assert!(b.extract_if(|k, _| *k < 3).eq([(1, 'a'), (2, 'b')]));
+    assert!(b.keys().eq([3].iter()));
+}
+
+fn hash<T: Hash> (value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
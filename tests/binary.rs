@@ -1,4 +1,7 @@
-use vector_mapp::binary::BinaryMap;
+#![cfg(feature = "alloc")]
+
+use core::ops::Bound;
+use vector_mapp::{binary::{BinaryMap, Entry}, vec::DuplicatePolicy};
 
 #[test]
 fn alpha () {
@@ -11,4 +14,256 @@ fn alpha () {
     assert_eq!(v.get("alex"), Some(&"andreba"));
     assert_eq!(v.get("rust 🦀"), Some(&"is awesome"));
     assert_eq!(v.get("python 🐍"), None);
-}
\ No newline at end of file
+}
+#[test]
+fn positional () {
+    let mut v = BinaryMap::new();
+    v.insert(3, "c");
+    v.insert(1, "a");
+    v.insert(2, "b");
+
+    assert_eq!(v.get_index_of(&2), Some(1));
+    assert_eq!(v.get_full(&3), Some((2, &3, &"c")));
+    assert_eq!(v.get_index(0), Some((&1, &"a")));
+    assert_eq!(v.remove_index(0), Some((1, "a")));
+    assert_eq!(v.remove_index(2), None);
+    assert_eq!(v.get_index_of(&2), Some(0));
+}
+
+#[test]
+fn insert_front_back () {
+    let mut v = BinaryMap::new();
+    assert_eq!(v.insert_front(5, "e"), Ok(()));
+    assert_eq!(v.insert_front(3, "c"), Ok(()));
+    assert_eq!(v.insert_back(7, "g"), Ok(()));
+
+    // the entries must stay sorted and unique, so equal or out of place keys are rejected
+    assert_eq!(v.insert_front(3, "x"), Err((3, "x")));
+    assert_eq!(v.insert_front(4, "d"), Err((4, "d")));
+    assert_eq!(v.insert_back(7, "x"), Err((7, "x")));
+    assert_eq!(v.insert_back(6, "f"), Err((6, "f")));
+
+    assert!(v.keys().eq([3, 5, 7].iter()));
+    assert_eq!(v.get(&3), Some(&"c"));
+    assert_eq!(v.get(&7), Some(&"g"));
+}
+
+#[test]
+fn filtering () {
+    let mut v = (0..10).rev().map(|x| (x, x * 10)).collect::<BinaryMap<_, _>>();
+    v.retain(|k, _| k % 3 != 0);
+    assert_eq!(v.extract_if(|k, _| k % 2 == 1).count(), 3);
+    assert!(v.keys().eq([2, 4, 8].iter()));
+
+    let (small, big) = v.partition(|k, _| *k < 5);
+    assert!(small.keys().eq([2, 4].iter()));
+    assert_eq!(big.get(&8), Some(&80));
+}
+
+#[test]
+fn entry_api () {
+    let mut v = BinaryMap::<String, i32>::new();
+    *v.entry("c".into()).or_insert(1) += 1;
+    *v.entry("c".into()).or_insert(10) += 1;
+    v.entry("b".into()).or_insert_with(|| 5);
+    v.entry("a".into()).or_insert_with_key(|k| k.len() as i32);
+    v.entry("d".into()).and_modify(|x| *x += 7).or_default();
+    v.entry("d".into()).and_modify(|x| *x += 7).or_default();
+    assert_eq!(v.entry("b".into()).key(), "b");
+    assert!(v.values().eq([1, 5, 3, 7].iter()));
+
+    let e = v.entry("bb".into()).insert_entry(9);
+    assert_eq!((e.key().as_str(), *e.get()), ("bb", 9));
+    assert_eq!(e.remove_entry(), ("bb".into(), 9));
+
+    match v.entry("b".into()) {
+        Entry::Occupied(e) => assert_eq!(e.replace_entry(6), ("b".into(), 5)),
+        Entry::Vacant(_) => unreachable!()
+    }
+    assert_eq!(v.get("b"), Some(&6));
+
+    let e = v.entry("z".into()).insert_entry(0);
+    assert_eq!(e.remove(), 0);
+    assert_eq!(v.get("z"), None);
+}
+
+#[test]
+fn try_insert () {
+    let mut v = BinaryMap::new();
+    assert_eq!(v.try_insert(2, "b").ok().copied(), Some("b"));
+    *v.try_insert(1, "a").unwrap() = "A";
+
+    let err = v.try_insert(1, "c").unwrap_err();
+    assert_eq!((*err.entry.key(), *err.entry.get(), err.value), (1, "A", "c"));
+    assert!(v.iter().eq([(&1, &"A"), (&2, &"b")]));
+}
+
+#[test]
+fn get_many () {
+    let mut v = (0..5).map(|x| (x, x * 10)).collect::<BinaryMap<_, _>>();
+
+    let [a, b] = v.get_many_mut([&4, &1]).unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(v.get_many([&1, &4]), Some([&40, &10]));
+    assert!(v.get_many_mut([&2, &2]).is_none());
+    assert!(v.get_many([&5]).is_none());
+}
+
+#[test]
+fn search_by () {
+    let mut v = [(1, 'a'), (2, 'b'), (3, 'c'), (11, 'd'), (12, 'e'), (21, 'f')].into_iter().collect::<BinaryMap<_, _>>();
+
+    assert_eq!(v.search_by(|k| k.cmp(&3)), Some((2, &3, &'c')));
+    assert_eq!(v.search_by(|k| k.cmp(&4)), None);
+    *v.search_by_mut(|k| k.cmp(&21)).unwrap().2 = 'F';
+    assert_eq!(v.get(&21), Some(&'F'));
+
+    let (range, iter) = v.range_by(|k| (k / 10).cmp(&1));
+    assert_eq!(range, 3..5);
+    assert!(iter.eq([(&11, &'d'), (&12, &'e')]));
+
+    let (range, mut iter) = v.range_by(|k| (k / 10).cmp(&5));
+    assert_eq!(range, 6..6);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn construction_dedup () {
+    #[cfg(feature = "allocator-api2")]
+    use allocator_api2::vec::Vec;
+
+    let entries = Vec::from([(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e')]);
+
+    let map = BinaryMap::from_vec(entries.clone());
+    assert_eq!(map.len(), 3);
+    assert!(map.iter().eq([(&1, &'e'), (&2, &'d'), (&3, &'c')]));
+    assert!(BinaryMap::from(entries.clone().into_boxed_slice()).iter().eq(&map));
+    assert!(BinaryMap::<_, _>::from([(1, 'a'), (1, 'b')]).iter().eq([(&1, &'b')]));
+
+    let first = BinaryMap::from_vec_with_policy(entries.clone(), DuplicatePolicy::KeepFirst).unwrap();
+    assert!(first.iter().eq([(&1, &'b'), (&2, &'d'), (&3, &'a')]));
+
+    let err = BinaryMap::from_box_with_policy(entries.into_boxed_slice(), DuplicatePolicy::Error).unwrap_err();
+    assert_eq!(err.indices(), &[2, 4]);
+}
+
+#[test]
+fn construction_keeps_first_key () {
+    #[cfg(feature = "allocator-api2")]
+    use allocator_api2::vec::Vec;
+    use core::cmp::Ordering;
+
+    // Compares by its first field only, so equal keys can still be told apart
+    #[derive(Debug, Clone, Copy)]
+    struct Tagged(u8, char);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+    }
+    impl Eq for Tagged {}
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering { self.0.cmp(&other.0) }
+    }
+
+    let entries = [(Tagged(2, 'a'), 1), (Tagged(1, 'b'), 2), (Tagged(2, 'c'), 3)];
+    let mut inserted = BinaryMap::new();
+    for (k, v) in entries {
+        inserted.insert(k, v);
+    }
+
+    let map = BinaryMap::from_vec_with_policy(Vec::from(entries), DuplicatePolicy::KeepLast).unwrap();
+    assert!(map.iter().map(|(k, v)| (k.1, *v)).eq([('b', 2), ('a', 3)]));
+    assert!(map.iter().map(|(k, v)| (k.1, *v)).eq(inserted.iter().map(|(k, v)| (k.1, *v))));
+}
+
+#[test]
+fn range () {
+    let mut b = (0..10).map(|x| (x * 10, x)).collect::<BinaryMap<_, _>>();
+    assert!(b.range(15..=40).eq([(&20, &2), (&30, &3), (&40, &4)]));
+    assert!(b.range(..20).rev().eq([(&10, &1), (&0, &0)]));
+    assert_eq!(b.range(35..).len(), 6);
+    assert_eq!(b.count_range((Bound::Excluded(0), Bound::Excluded(30))), 2);
+    assert_eq!(b.count_range(100..), 0);
+
+    for (_, v) in b.range_mut(70..) {
+        *v *= 2;
+    }
+    assert!(b.values().skip(7).eq([14, 16, 18].iter()));
+
+    assert!(b.remove_range(20..50).eq([(20, 2), (30, 3), (40, 4)]));
+    assert!(b.keys().eq([0, 10, 50, 60, 70, 80, 90].iter()));
+    drop(b.remove_range(..=60));
+    assert_eq!(b.len(), 3);
+}
+
+#[test]
+fn first_last () {
+    let mut b = BinaryMap::<_, _>::from_iter([(3, 'c'), (1, 'a'), (2, 'b'), (4, 'd')]);
+    assert_eq!(b.first_key_value(), Some((&1, &'a')));
+    assert_eq!(b.last_key_value(), Some((&4, &'d')));
+
+    *b.first_entry().unwrap().get_mut() = 'x';
+    assert_eq!(b.last_entry().map(|entry| entry.index()), Some(3));
+    assert_eq!(b.last_entry().map(|entry| entry.remove()), Some('d'));
+    assert_eq!(b.pop_first(), Some((1, 'x')));
+    assert_eq!(b.pop_last(), Some((3, 'c')));
+    assert_eq!(b.pop_first(), Some((2, 'b')));
+
+    assert_eq!(b.pop_last(), None);
+    assert_eq!(b.first_key_value(), None);
+    assert!(b.first_entry().is_none() && b.last_entry().is_none());
+}
+
+#[test]
+fn literal () {
+    let b = vector_mapp::binarymap! { "c" => 3, "a" => 1, "b" => 2 };
+    assert!(b.iter().eq([(&"a", &1), (&"b", &2), (&"c", &3)]));
+    assert_eq!(b.capacity(), 3);
+}
+
+#[test]
+fn capacity () {
+    let mut v = BinaryMap::<u64, u64>::new();
+    v.reserve_exact(10);
+    assert!(v.capacity() >= 10);
+    v.shrink_to(0);
+    assert_eq!(v.capacity(), 0);
+
+    assert!(v.try_reserve_exact(usize::MAX).is_err());
+    assert_eq!(v.try_insert_alloc(3, 3), Ok(None));
+    assert!(v.try_extend([(2, 2), (3, 4), (1, 1)]).is_ok());
+    assert!(v.iter().eq([(&1, &1), (&2, &2), (&3, &4)]));
+
+    match v.entry(0) {
+        Entry::Vacant(entry) => assert_eq!(entry.try_insert(0).map(|x| *x), Ok(0)),
+        Entry::Occupied(_) => unreachable!()
+    }
+    assert_eq!(v.first_key_value(), Some((&0, &0)));
+}
+
+#[test]
+fn set_algebra () {
+    let a = (0..6).map(|x| (x, 'a')).collect::<BinaryMap<_, _>>();
+    let b = (3..9).map(|x| (x, 'b')).collect::<BinaryMap<_, _>>();
+
+    assert!(a.union(&b).map(|(k, _)| *k).eq(0..9));
+    assert!(a.union(&b).filter(|(_, v)| **v == 'a').map(|(k, _)| *k).eq(0..6));
+    assert!(a.intersection(&b).eq([(&3, &'a'), (&4, &'a'), (&5, &'a')]));
+    assert!(a.difference(&b).map(|(k, _)| *k).eq(0..3));
+    assert!(b.difference(&a).map(|(k, _)| *k).eq(6..9));
+    assert!(a.symmetric_difference(&b).map(|(k, _)| *k).eq([0, 1, 2, 6, 7, 8]));
+
+    let inner = &(&a & &b) - &(2..4).map(|x| (x, 'c')).collect();
+    assert!(inner.keys().eq([4, 5].iter()));
+    assert!((&a | &b).keys().eq((0..9).collect::<Vec<_>>().iter()));
+    assert_eq!((&a ^ &b).len(), 6);
+
+    assert!(inner.is_subset(&a) && inner.is_subset(&b));
+    assert!(a.is_superset(&inner));
+    assert!(!a.is_subset(&b));
+    assert!(a.is_disjoint(&(6..9).map(|x| (x, 'c')).collect()));
+    assert!(!a.is_disjoint(&b));
+}
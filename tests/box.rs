@@ -0,0 +1,53 @@
+#![cfg(feature = "alloc")]
+
+use vector_mapp::{r#box::BoxMap, vec::{DuplicatePolicy, VecMap}};
+
+#[test]
+fn filtering () {
+    let v = (0..6).map(|x| (x, x * 10)).collect::<BoxMap<_, _>>();
+    let v = v.retain(|k, _| *k != 0);
+    assert_eq!(v.len(), 5);
+
+    let (v, odd) = v.extract_if(|k, _| k % 2 == 1);
+    assert_eq!(odd.collect::<VecMap<_, _>>().len(), 3);
+    assert!(v.keys().eq([2, 4].iter()));
+
+    let (two, four) = v.partition(|k, _| *k == 2);
+    assert_eq!(two.get(&2), Some(&20));
+    assert_eq!(four.get(&4), Some(&40));
+}
+
+#[test]
+fn get_many () {
+    let mut v = (0..5).map(|x| (x, x * 10)).collect::<BoxMap<_, _>>();
+
+    let [a, b, c] = v.get_many_mut([&0, &2, &4]).unwrap();
+    *a += 1;
+    *b += 2;
+    *c += 4;
+    assert_eq!(v.get_many([&0, &2, &4]), Some([&1, &22, &44]));
+    assert!(v.get_many_mut([&0, &2, &0]).is_none());
+}
+
+#[test]
+fn checked_constructors () {
+    #[cfg(feature = "allocator-api2")]
+    use allocator_api2::vec::Vec;
+
+    let entries = Vec::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+
+    let map = BoxMap::from_box(entries.clone().into_boxed_slice(), DuplicatePolicy::KeepLast).unwrap();
+    assert!(map.iter().eq([(&1, &'c'), (&2, &'b')]));
+
+    let err = BoxMap::from_box_ord(entries.clone().into_boxed_slice(), DuplicatePolicy::Error).unwrap_err();
+    assert_eq!(err.indices(), &[2]);
+    assert_eq!(err.to_string(), "found duplicate keys at indices [2]");
+    assert!(BoxMap::<_, _>::try_from(entries).is_err());
+}
+
+#[test]
+fn literal () {
+    let v = vector_mapp::boxmap! { 'b' => 2, 'a' => 1 };
+    assert!(v.keys().eq(['b', 'a'].iter()));
+    assert_eq!(v.get(&'a'), Some(&1));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "alloc")]
+
+use vector_mapp::{set::VecSet, soa::SoaVecMap, vec::VecMap};
+
+/// Looks up every key of maps of up to 40 entries, which covers the full chunks of every key width, the
+/// partial chunk after them, and the keys of a wide map that only differ in one half of their bits.
+macro_rules! test_chunks {
+    ($($name:ident: $t:ty => |$i:ident| $key:expr),+) => {
+        $(
+            #[test]
+            fn $name () {
+                let key = |$i: usize| -> $t { $key };
+
+                for len in 0..=40 {
+                    let vec = (0..len).map(|i| (key(i), i)).collect::<VecMap<_, _>>();
+                    let unit = (0..len).map(|i| (key(i), ())).collect::<VecMap<_, _>>();
+                    let set = (0..len).map(key).collect::<VecSet<_>>();
+                    let soa = (0..len).map(|i| (key(i), i)).collect::<SoaVecMap<_, _>>();
+                    let boxed = (0..len).map(|i| (Box::new(key(i)), i)).collect::<VecMap<_, _>>();
+
+                    for i in 0..len {
+                        assert_eq!(vec.get_index_of(&key(i)), Some(i), "{len} entries");
+                        assert_eq!(unit.get_index_of(&key(i)), Some(i), "{len} entries");
+                        assert!(set.contains(&key(i)), "{len} entries");
+                        assert_eq!(soa.get_index_of(&key(i)), Some(i), "{len} entries");
+                        assert_eq!(boxed.get_index_of(&key(i)), Some(i), "{len} entries");
+                    }
+
+                    assert_eq!(vec.get_index_of(&key(len)), None);
+                    assert_eq!(unit.get_index_of(&key(len)), None);
+                    assert!(!set.contains(&key(len)));
+                    assert_eq!(soa.get_index_of(&key(len)), None);
+                    assert_eq!(boxed.get_index_of(&key(len)), None);
+                }
+            }
+        )+
+    };
+}
+
+test_chunks! {
+    chunks_u8: u8 => |i| (i * 3) as u8,
+    chunks_u16: u16 => |i| (i * 257) as u16,
+    chunks_u32: u32 => |i| (i * 65537) as u32,
+    chunks_u64: u64 => |i| ((i as u64) << 32) | 5,
+    chunks_char: char => |i| char::from_u32(0x1F600 + i as u32).unwrap()
+}
+
+#[test]
+fn last_partial_chunk () {
+    // 16 `u8` keys fill a chunk, so the 17th and 18th are found in the remainder
+    let vec = (0..18u8).map(|i| (i, ())).collect::<VecMap<_, _>>();
+    assert_eq!(vec.get_index_of(&17), Some(17));
+    let set = (0..18u8).collect::<VecSet<_>>();
+    assert!(set.contains(&16) && !set.contains(&18));
+
+    // 4 `u64` keys fill a chunk, so a map of 7 ends with a partial one of 3
+    let soa = (0..7u64).map(|i| (i, i)).collect::<SoaVecMap<_, _>>();
+    assert_eq!(soa.get(&6), Some(&6));
+    assert_eq!(soa.get(&7), None);
+}
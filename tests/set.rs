@@ -0,0 +1,74 @@
+#![cfg(feature = "alloc")]
+
+use vector_mapp::{array::ArrayVec, set::{BinarySet, BoxSet, VecSet}};
+
+#[test]
+fn vec () {
+    let mut set = VecSet::new();
+    assert!(set.insert(3));
+    assert!(set.insert(1));
+    assert!(!set.insert(3));
+    assert_eq!(set.len(), 2);
+
+    assert!(set.contains(&1));
+    assert_eq!(set.get(&3), Some(&3));
+    assert_eq!(set.replace(1), Some(1));
+    assert_eq!(set.take(&3), Some(3));
+    assert!(!set.remove(&3));
+    assert!(set.remove(&1));
+    assert!(set.is_empty());
+
+    let set = [1, 2, 2, 3].into_iter().collect::<VecSet<_>>();
+    assert_eq!(set.len(), 3);
+    assert_eq!(set, [3, 2, 1].into_iter().collect::<VecSet<_>>());
+    assert_eq!(set.into_iter().sum::<i32>(), 6);
+}
+
+#[test]
+fn binary () {
+    let mut set = [5, 1, 4, 1, 3].into_iter().collect::<BinarySet<_>>();
+    assert!(set.iter().eq([1, 3, 4, 5].iter()));
+    assert_eq!(set.first(), Some(&1));
+    assert_eq!(set.last(), Some(&5));
+
+    assert!(set.insert(2));
+    assert!(!set.insert(4));
+    assert!(set.range(2..5).eq([2, 3, 4].iter()));
+    assert!(set.range(..=2).eq([1, 2].iter()));
+    assert!(set.range((core::ops::Bound::Excluded(3), core::ops::Bound::Unbounded)).eq([4, 5].iter()));
+    assert_eq!(set.range(6..).len(), 0);
+
+    set.retain(|x| x % 2 == 1);
+    assert!(set.into_iter().eq([1, 3, 5]));
+}
+
+#[test]
+fn boxed () {
+    let set = [1, 2, 3, 2].into_iter().collect::<BoxSet<_>>();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&2));
+
+    let set = set.retain(|x| *x != 2);
+    assert!(!set.contains(&2));
+    assert_eq!(format!("{set:?}"), "{1, 3}");
+
+    let set = VecSet::from(set);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn fixed_storage () {
+    let mut set = VecSet::<_, ArrayVec<_, 2>>::default();
+    assert_eq!(set.checked_insert(1), Ok(true));
+    assert_eq!(set.checked_insert(1), Ok(false));
+    assert_eq!(set.checked_insert(2), Ok(true));
+    assert_eq!(set.checked_insert(3), Err(3));
+    assert_eq!(set, [2, 1].into_iter().collect::<VecSet<_>>());
+
+    let mut set = BinarySet::<_, ArrayVec<_, 3>>::default();
+    assert_eq!(set.checked_insert(3), Ok(true));
+    assert_eq!(set.checked_insert(1), Ok(true));
+    assert!(set.iter().eq([1, 3].iter()));
+    assert!(set.take(&1).is_some());
+    assert_eq!(set.first(), Some(&3));
+}
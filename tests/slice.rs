@@ -0,0 +1,99 @@
+use vector_mapp::slice::{SliceMap, SliceMapMut, StaticBinaryMap};
+
+static ENTRIES: [(u8, char); 3] = [(1, 'a'), (2, 'b'), (4, 'd')];
+static MAP: SliceMap<'static, u8, char> = SliceMap::new(&ENTRIES);
+
+#[test]
+fn map () {
+    assert_eq!(MAP.len(), 3);
+    assert!(MAP.is_sorted());
+
+    assert_eq!(MAP.get(&2), Some(&'b'));
+    assert_eq!(MAP.get(&3), None);
+    assert_eq!(MAP.get_index_of(&4), Some(2));
+    assert_eq!(MAP.binary_get(&4), Some(&'d'));
+    assert_eq!(MAP.binary_get_key_value(&1), Some((&1, &'a')));
+    assert!(!MAP.binary_contains_key(&3));
+    assert_eq!(MAP[&1], 'a');
+
+    assert!(MAP.keys().eq([1, 2, 4].iter()));
+    assert!(MAP.values().eq(['a', 'b', 'd'].iter()));
+
+    let unsorted = SliceMap::new(&[(2, ()), (1, ())]);
+    assert!(!unsorted.is_sorted());
+    assert!(unsorted.contains_key(&1));
+    assert!(!SliceMap::new(&[(1, ()), (1, ())]).is_sorted());
+}
+
+#[test]
+fn map_mut () {
+    let mut entries = [(3, 30), (1, 10), (2, 20)];
+    let mut map = SliceMapMut::new(&mut entries);
+
+    *map.get_mut(&1).unwrap() += 1;
+    for v in map.values_mut() {
+        *v *= 2;
+    }
+    assert!(map.iter().eq([(&3, &60), (&1, &22), (&2, &40)]));
+    assert!(!map.as_map().is_sorted());
+
+    entries.sort_unstable_by_key(|(k, _)| *k);
+    let mut map = SliceMapMut::from(&mut entries[..]);
+    *map.binary_get_mut(&3).unwrap() = 0;
+    assert_eq!(map.binary_get(&3), Some(&0));
+    assert_eq!(map.get_index_mut(0), Some((&1, &mut 22)));
+    assert_eq!(entries, [(1, 22), (2, 40), (3, 0)]);
+}
+
+vector_mapp::static_binary_map! {
+    static OPCODES: u8 => &str = { 0x00 => "nop", 0x01 => "load", 0x10 => "store" };
+    const UNITS: &str => u32 = {
+        "cm" => 10,
+        "km" => 1_000_000,
+        "m" => 1000,
+        "mm" => 1,
+    };
+}
+
+const LOAD: Option<&str> = match OPCODES.get(0x01) {
+    Some(name) => Some(*name),
+    None => None,
+};
+
+#[test]
+fn static_map () {
+    assert_eq!(LOAD, Some("load"));
+    assert_eq!(OPCODES.get(0x02), None);
+    assert_eq!(OPCODES.get_index_of(0x10), Some(2));
+    assert!(OPCODES.keys().eq([0x00, 0x01, 0x10].iter()));
+
+    const { assert!(UNITS.contains_key("mm")) };
+    assert_eq!(UNITS.get_key_value("m"), Some((&"m", &1000)));
+    assert_eq!(UNITS.get("k"), None);
+    assert_eq!(UNITS.last_key_value(), Some((&"mm", &1)));
+
+    let letters = StaticBinaryMap::<char, u32>::new(&[('a', 1), ('b', 2)]);
+    assert_eq!(letters.get('b'), Some(&2));
+    assert_eq!(letters.as_map().binary_get(&'a'), Some(&1));
+
+    let pairs = unsafe { StaticBinaryMap::from_sorted_unchecked(&[((0, 1), 'a'), ((1, 0), 'b')]) };
+    assert_eq!(pairs.search_by(|k| k.cmp(&(1, 0))), Some((1, &(1, 0), &'b')));
+}
+
+#[test]
+fn static_map_prefix_keys () {
+    const WORDS: StaticBinaryMap<&str, usize> = StaticBinaryMap::<&str, usize>::new(&[("", 0), ("a", 1), ("ab", 2), ("abc", 3), ("b", 4)]);
+
+    for (i, key) in ["", "a", "ab", "abc", "b"].into_iter().enumerate() {
+        assert_eq!(WORDS.get_index_of(key), Some(i));
+    }
+    for key in ["aa", "abcd", "abd", "ba", "c"] {
+        assert!(!WORDS.contains_key(key));
+    }
+}
+
+#[test]
+#[should_panic = "sorted and unique"]
+fn static_map_unsorted () {
+    StaticBinaryMap::<u8, ()>::new(&[(1, ()), (1, ())]);
+}
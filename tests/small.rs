@@ -0,0 +1,140 @@
+#![cfg(feature = "alloc")]
+
+use vector_mapp::{small::{SmallBinaryMap, SmallVecMap}, vec::Entry};
+
+#[test]
+fn vec () {
+    let mut v = SmallVecMap::<_, _, 2>::new();
+    assert_eq!(v.insert(1, 'a'), None);
+    assert_eq!(v.insert(2, 'b'), None);
+    assert!(v.is_inline());
+    assert_eq!(v.capacity(), 2);
+
+    assert_eq!(v.insert(3, 'c'), None);
+    assert!(!v.is_inline());
+    assert_eq!(v.insert(1, 'd'), Some('a'));
+    assert!(v.iter().eq([(&1, &'d'), (&2, &'b'), (&3, &'c')]));
+
+    assert_eq!(v.shift_remove(&1), Some('d'));
+    v.shrink_to_fit();
+    assert!(v.is_inline());
+    assert!(v.iter().eq([(&2, &'b'), (&3, &'c')]));
+
+    match v.entry(4) {
+        Entry::Vacant(entry) => *entry.insert('x') = 'e',
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert!(!v.is_inline());
+    assert_eq!(v[&4], 'e');
+    assert!(v.clone().into_iter().eq([(2, 'b'), (3, 'c'), (4, 'e')]));
+
+    v.clear();
+    assert!(v.is_inline());
+    assert_eq!(v.capacity(), 2);
+    assert_eq!(v.insert(5, 'f'), None);
+    assert!(v.iter().eq([(&5, &'f')]));
+}
+
+#[test]
+fn binary () {
+    let mut v = [(3, 'c'), (1, 'a')].into_iter().collect::<SmallBinaryMap<_, _, 4>>();
+    assert!(v.is_inline());
+
+    v.extend([(5, 'e'), (2, 'b'), (4, 'd')]);
+    assert!(!v.is_inline());
+    assert!(v.keys().eq([1, 2, 3, 4, 5].iter()));
+
+    *v.entry(0).or_insert('x') = 'z';
+    assert_eq!(v.get(&0), Some(&'z'));
+
+    v.retain(|k, _| k % 2 == 0);
+    assert!(!v.is_inline());
+    v.shrink_to_fit();
+    assert!(v.is_inline());
+    assert!(v.into_iter().rev().eq([(4, 'd'), (2, 'b'), (0, 'z')]));
+}
+
+#[test]
+fn vec_positional () {
+    let mut v = (0..5).map(|x| (x, x * 10)).collect::<SmallVecMap<_, _, 4>>();
+    v.sort_by(|(x, _), (y, _)| y.cmp(x));
+    assert!(v.keys().eq([4, 3, 2, 1, 0].iter()));
+    v.sort_keys();
+
+    v.move_index(0, 2);
+    v.swap_indices(0, 4);
+    assert!(v.keys().eq([4, 2, 0, 3, 1].iter()));
+    assert_eq!(v.find_by(|k| *k == 3), Some((3, &3, &30)));
+    assert_eq!(v.get_full(&0), Some((2, &0, &0)));
+
+    assert_eq!(v.insert_at(0, 1, 11), Some(10));
+    assert_eq!(v.insert_at(5, 7, 70), None);
+    assert!(v.keys().eq([1, 4, 2, 0, 3, 7].iter()));
+
+    assert_eq!(v.get_many([&2, &7]), Some([&20, &70]));
+    assert_eq!(v.get_many_mut([&4, &4]), None);
+
+    // Extracting from the heap buffer
+    let odd = v.extract_if(|k, _| k % 2 == 1).collect::<Vec<_>>();
+    assert_eq!(odd, [(1, 11), (3, 30), (7, 70)]);
+    assert!(v.keys().eq([4, 2, 0].iter()));
+
+    // And from the inline one, stopping early
+    v.shrink_to_fit();
+    assert!(v.is_inline());
+    assert_eq!(v.extract_if(|k, _| *k < 3).next(), Some((2, 20)));
+    assert!(v.keys().eq([4, 0].iter()));
+
+    let (even, odd) = (0..6).map(|x| (x, x)).collect::<SmallVecMap<_, _, 4>>().partition(|k, _| k % 2 == 0);
+    assert!(even.keys().eq([0, 2, 4].iter()));
+    assert!(odd.keys().eq([1, 3, 5].iter()));
+}
+
+#[test]
+fn binary_ranges () {
+    let mut v = (0..6).map(|x| (x, x * 10)).collect::<SmallBinaryMap<_, _, 8>>();
+    assert!(v.range(2..4).eq([(&2, &20), (&3, &30)]));
+    assert_eq!(v.count_range(..=3), 4);
+    v.range_mut(4..).for_each(|(_, v)| *v += 1);
+    assert_eq!(v.values().copied().collect::<Vec<_>>(), [0, 10, 20, 30, 41, 51]);
+
+    let (range, mut iter) = v.range_by(|k| (k / 2).cmp(&1));
+    assert_eq!(range, 2..4);
+    assert_eq!(iter.next(), Some((&2, &20)));
+    assert_eq!(v.search_by(|k| k.cmp(&5)), Some((5, &5, &51)));
+
+    assert_eq!(v.first_key_value(), Some((&0, &0)));
+    assert_eq!(v.last_key_value(), Some((&5, &51)));
+    assert_eq!(v.pop_first(), Some((0, 0)));
+    assert_eq!(v.pop_last(), Some((5, 51)));
+    assert_eq!(v.get_many([&1, &4]), Some([&10, &41]));
+
+    let (lo, hi) = v.partition(|k, _| *k < 3);
+    assert!(lo.keys().eq([1, 2].iter()));
+    assert!(hi.keys().eq([3, 4].iter()));
+}
+
+#[test]
+fn heap_storage () {
+    let mut v = (0..3).map(|x| (x, x)).collect::<SmallBinaryMap<_, _, 4>>();
+    assert!(v.remove_range(1..).eq([(1, 1), (2, 2)]));
+    assert!(v.is_inline());
+
+    v.extend((3..8).map(|x| (x, x)));
+    assert!(!v.is_inline());
+    assert!(v.remove_range(..5).rev().eq([(4, 4), (3, 3), (0, 0)]));
+    assert!(v.keys().eq([5, 6, 7].iter()));
+
+    let mut v = SmallVecMap::<_, _, 2>::with_capacity(1);
+    assert!(v.is_inline());
+    v.extend([(1, 'a'), (2, 'b')]);
+    assert!(v.drain().eq([(1, 'a'), (2, 'b')]));
+    assert!(v.is_empty());
+
+    v.extend([(3, 'c'), (4, 'd'), (5, 'e')]);
+    assert_eq!(*v.clone().into_inner().into_box(), [(3, 'c'), (4, 'd'), (5, 'e')]);
+    assert_eq!(v.remove(&4), Some('d'));
+    v.shrink_to_fit();
+    assert!(v.is_inline());
+    assert_eq!(*v.into_inner().into_box(), [(3, 'c'), (5, 'e')]);
+}
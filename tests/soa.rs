@@ -0,0 +1,170 @@
+#![cfg(feature = "alloc")]
+
+use vector_mapp::{soa::{Entry, SoaBinaryMap, SoaBoxMap, SoaVecMap}, vec::{Ordered, Unordered}};
+
+#[test]
+fn vec () {
+    let mut v = SoaVecMap::new();
+    v.insert("hello", 1);
+    v.insert("alex", 2);
+    v.insert("rust 🦀", 3);
+
+    assert_eq!(v.get("alex"), Some(&2));
+    assert_eq!(v.keys_slice(), &["hello", "alex", "rust 🦀"]);
+    assert_eq!(v.values_slice(), &[1, 2, 3]);
+
+    match v.entry("hello") {
+        Entry::Occupied(mut e) => *e.get_mut() += 10,
+        Entry::Vacant(_) => unreachable!()
+    }
+    assert_eq!(v["hello"], 11);
+
+    assert_eq!(v.remove("hello"), Some(11));
+    assert_eq!(v.keys_slice(), &["rust 🦀", "alex"]);
+
+    v.retain(|_, v| *v > 2);
+    assert!(v.iter().eq([(&"rust 🦀", &3)]));
+}
+
+#[test]
+fn vec_ordered () {
+    let mut v = SoaVecMap::with_policy(Ordered);
+    v.insert("a", 1);
+    v.insert("b", 2);
+    v.insert("c", 3);
+    v.insert("d", 4);
+
+    assert_eq!(v.remove("b"), Some(2));
+    assert_eq!(v.keys_slice(), &["a", "c", "d"]);
+
+    match v.entry("a") {
+        Entry::Occupied(x) => assert_eq!(x.remove(), 1),
+        Entry::Vacant(_) => unreachable!()
+    }
+    assert_eq!(v.keys_slice(), &["c", "d"]);
+    assert_eq!(v.values_slice(), &[3, 4]);
+
+    let mut v = v.into_policy::<Unordered>();
+    v.insert("e", 5);
+    v.insert("f", 6);
+    assert_eq!(v.remove("c"), Some(3));
+    assert_eq!(v.keys_slice(), &["f", "d", "e"]);
+}
+
+#[test]
+fn entry () {
+    let mut v = SoaVecMap::new();
+    assert_eq!(*v.entry("a").or_insert(1), 1);
+    assert_eq!(*v.entry("a").or_insert(10), 1);
+    assert_eq!(*v.entry("b").or_insert_with(|| 2), 2);
+    assert_eq!(*v.entry("abc").or_insert_with_key(|k| k.len()), 3);
+    assert_eq!(*v.entry("d").or_default(), 0);
+
+    *v.entry("d").and_modify(|x| *x += 4).or_default() += 1;
+    assert_eq!(v["d"], 5);
+
+    let e = v.entry("e").insert_entry(6);
+    assert_eq!((e.key(), e.get()), (&"e", &6));
+    let mut e = v.entry("a").insert_entry(7);
+    assert_eq!(e.insert(8), 7);
+
+    assert_eq!(v.keys_slice(), &["a", "b", "abc", "d", "e"]);
+    assert_eq!(v.values_slice(), &[8, 2, 3, 5, 6]);
+
+    let mut v = (0..4).map(|x| (x * 2, x)).collect::<SoaBinaryMap<_, _>>();
+    *v.entry(3).or_default() += 10;
+    assert_eq!(*v.entry(4).or_insert(20), 2);
+    assert_eq!(v.keys_slice(), &[0, 2, 3, 4, 6]);
+    assert_eq!(v.values_slice(), &[0, 1, 10, 2, 3]);
+}
+
+#[test]
+fn binary () {
+    let mut v = (0..6).rev().map(|x| (x, x * 10)).collect::<SoaBinaryMap<_, _>>();
+    assert_eq!(v.keys_slice(), &[0, 1, 2, 3, 4, 5]);
+
+    if let Entry::Vacant(e) = v.entry(7) {
+        e.insert(70);
+    }
+    assert_eq!(v.remove(&0), Some(0));
+    assert_eq!(v.get_index_of(&7), Some(5));
+    assert_eq!(v.pop(), Some((7, 70)));
+}
+
+#[test]
+fn vec_positional () {
+    let mut v = (0..6).map(|x| (x, x * 10)).collect::<SoaVecMap<_, _>>();
+    v.sort_by(|(x, _), (y, _)| y.cmp(x));
+    assert_eq!(v.keys_slice(), &[5, 4, 3, 2, 1, 0]);
+    assert_eq!(v.values_slice(), &[50, 40, 30, 20, 10, 0]);
+    v.sort_keys();
+    assert_eq!(v.values_slice(), &[0, 10, 20, 30, 40, 50]);
+
+    v.move_index(0, 3);
+    assert_eq!(v.keys_slice(), &[1, 2, 3, 0, 4, 5]);
+    v.swap_indices(0, 5);
+    assert_eq!(v.values_slice(), &[50, 20, 30, 0, 40, 10]);
+    assert_eq!(v.find_by(|k| *k == 3), Some((2, &3, &30)));
+    assert_eq!(v.shift_remove_index(0), Some((5, 50)));
+    assert_eq!(v.swap_remove_index(0), Some((2, 20)));
+    assert_eq!(v.keys_slice(), &[1, 3, 0, 4]);
+
+    assert_eq!(v.insert_at(0, 4, 44), Some(40));
+    assert_eq!(v.insert_at(4, 9, 90), None);
+    assert_eq!(v.keys_slice(), &[4, 1, 3, 0, 9]);
+
+    assert_eq!(v.get_many([&0, &9]), Some([&0, &90]));
+    assert_eq!(v.get_many_mut([&1, &1]), None);
+    if let Some([a, b]) = v.get_many_mut([&1, &3]) {
+        core::mem::swap(a, b);
+    }
+    assert_eq!(v.values_slice(), &[44, 30, 10, 0, 90]);
+
+    let odd = v.extract_if(|k, _| k % 2 == 1).collect::<Vec<_>>();
+    assert_eq!(odd, [(1, 30), (3, 10), (9, 90)]);
+    assert_eq!(v.keys_slice(), &[4, 0]);
+
+    let (even, odd) = (0..6).map(|x| (x, x)).collect::<SoaVecMap<_, _>>().partition(|k, _| k % 2 == 0);
+    assert_eq!(even.keys_slice(), &[0, 2, 4]);
+    assert_eq!(odd.values_slice(), &[1, 3, 5]);
+}
+
+#[test]
+fn binary_ordered () {
+    let mut v = (0..6).map(|x| (x, x * 10)).collect::<SoaBinaryMap<_, _>>();
+    match v.entry(1) {
+        Entry::Occupied(e) => assert_eq!(e.remove_entry(), (1, 10)),
+        Entry::Vacant(_) => unreachable!()
+    }
+    assert_eq!(v.keys_slice(), &[0, 2, 3, 4, 5]);
+    assert_eq!(v.get(&5), Some(&50));
+
+    assert_eq!(v.first_key_value(), Some((&0, &0)));
+    assert_eq!(v.last_key_value(), Some((&5, &50)));
+    assert_eq!(v.get_many([&2, &4]), Some([&20, &40]));
+    assert_eq!(v.get_many_mut([&2, &2]), None);
+
+    let mut odd = v.extract_if(|k, _| k % 2 == 1);
+    assert_eq!(odd.next(), Some((3, 30)));
+    drop(odd);
+    assert_eq!(v.keys_slice(), &[0, 2, 4, 5]);
+
+    let (lo, hi) = v.partition(|k, _| *k < 3);
+    assert_eq!(lo.keys_slice(), &[0, 2]);
+    assert_eq!(hi.keys_slice(), &[4, 5]);
+
+    let mut hi = hi;
+    assert_eq!(hi.pop_first(), Some((4, 40)));
+    assert_eq!(hi.pop_last(), Some((5, 50)));
+    assert_eq!(hi.pop_last(), None);
+}
+
+#[test]
+fn boxed () {
+    let v = (0..6).map(|x| (x, x * 10)).collect::<SoaBoxMap<_, _>>();
+    let v = v.retain(|k, _| k % 2 == 0);
+    assert_eq!(v.values_slice(), &[0, 20, 40]);
+
+    let v = SoaVecMap::from(v);
+    assert_eq!(v.get(&4), Some(&40));
+}
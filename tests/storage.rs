@@ -0,0 +1,81 @@
+use core::mem::MaybeUninit;
+use std::rc::Rc;
+use vector_mapp::{array::ArrayVec, binary::{BinaryMap, Entry}, storage::SliceVec, vec::VecMap};
+
+#[test]
+fn array () {
+    let mut v = VecMap::<_, _, ArrayVec<_, 3>>::default();
+    assert_eq!(v.checked_insert(1, 'a'), Ok(None));
+    assert_eq!(v.checked_insert(2, 'b'), Ok(None));
+    assert_eq!(v.checked_insert(1, 'c'), Ok(Some('a')));
+    assert_eq!(v.checked_insert(3, 'd'), Ok(None));
+    assert_eq!(v.checked_insert(4, 'e'), Err((4, 'e')));
+
+    assert_eq!(v.remove(&1), Some('c'));
+    assert!(v.iter().eq([(&3, &'d'), (&2, &'b')]));
+    assert_eq!(v.shift_remove(&3), Some('d'));
+    assert_eq!(v[&2], 'b');
+
+    let mut b = BinaryMap::<_, _, ArrayVec<_, 4>>::default();
+    assert_eq!(b.checked_extend([(3, 'c'), (1, 'a'), (2, 'b')]), Ok(()));
+    match b.entry(2) {
+        Entry::Occupied(mut entry) => *entry.get_mut() = 'y',
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(b.iter().eq([(&1, &'a'), (&2, &'y'), (&3, &'c')]));
+    b.retain(|k, _| k % 2 == 1);
+    assert!(b.keys().eq([1, 3].iter()));
+    assert_eq!(b.search_by(|k| k.cmp(&3)), Some((1, &3, &'c')));
+}
+
+#[test]
+fn slice () {
+    let value = Rc::new(());
+    let mut buf = [const { MaybeUninit::uninit() }; 2];
+    {
+        let mut b = BinaryMap::from_storage(SliceVec::new(&mut buf)).unwrap();
+        assert_eq!(b.checked_insert(2, value.clone()), Ok(None));
+        assert!(b.checked_insert(1, value.clone()).is_ok());
+        assert!(b.checked_insert(3, value.clone()).is_err());
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        match b.entry(1) {
+            Entry::Occupied(entry) => drop(entry.remove()),
+            Entry::Vacant(_) => unreachable!(),
+        }
+        assert!(b.keys().eq([2].iter()));
+        assert_eq!(b.storage().capacity(), 2);
+        assert_eq!(Rc::strong_count(&value), 2);
+    }
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let mut buf = [const { MaybeUninit::uninit() }; 4];
+    let mut storage = SliceVec::new(&mut buf);
+    storage.push((2, 'b')).unwrap();
+    storage.push((1, 'a')).unwrap();
+    let storage = BinaryMap::from_storage(storage).unwrap_err();
+    let v = VecMap::from_storage(storage).unwrap();
+    assert_eq!(v.get(&1), Some(&'a'));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec () {
+    #[cfg(feature = "allocator-api2")]
+    use allocator_api2::vec::Vec;
+
+    assert!(VecMap::from_storage(Vec::from([(1, 'a'), (1, 'b')])).is_err());
+    assert!(BinaryMap::from_storage(Vec::from([(2, 'b'), (1, 'a')])).is_err());
+
+    let mut v = VecMap::from_storage(Vec::from([(1, 'a'), (2, 'b')])).unwrap();
+    assert_eq!(v.checked_insert(3, 'c'), Ok(None));
+    assert!(v.iter().eq([(&1, &'a'), (&2, &'b'), (&3, &'c')]));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless () {
+    let mut v = VecMap::<_, _, heapless::Vec<_, 2>>::default();
+    assert_eq!(v.checked_extend([(1, 'a'), (2, 'b'), (3, 'c')]), Err((3, 'c')));
+    assert_eq!(v.get(&2), Some(&'b'));
+}
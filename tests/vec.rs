@@ -1,4 +1,6 @@
-use vector_mapp::vec::VecMap;
+#![cfg(feature = "alloc")]
+
+use vector_mapp::{array::ArrayVec, vec::{DuplicatePolicy, Entry, Ordered, SelfOrganizingVecMap, Transpose, Unordered, VecMap}};
 
 #[test]
 fn alpha () {
@@ -11,4 +13,364 @@ fn alpha () {
     assert_eq!(v.get("alex"), Some(&"andreba"));
     assert_eq!(v.get("rust 🦀"), Some(&"is awesome"));
     assert_eq!(v.get("python 🐍"), None);
-}
\ No newline at end of file
+}
+#[test]
+fn ordered () {
+    let mut v = VecMap::with_policy(Ordered);
+    v.insert("a", 1);
+    v.insert("b", 2);
+    v.insert("c", 3);
+    v.insert("d", 4);
+
+    assert_eq!(v.remove("b"), Some(2));
+    assert!(v.keys().eq(["a", "c", "d"].iter()));
+
+    match v.entry("a") {
+        Entry::Occupied(x) => assert_eq!(x.remove(), 1),
+        Entry::Vacant(_) => unreachable!()
+    }
+    assert!(v.keys().eq(["c", "d"].iter()));
+
+    let mut v = v.into_policy::<Unordered>();
+    v.insert("e", 5);
+    assert_eq!(v.shift_remove("c"), Some(3));
+    assert!(v.keys().eq(["d", "e"].iter()));
+}
+
+#[test]
+fn positional () {
+    let mut v = VecMap::new();
+    v.insert("a", 1);
+    v.insert("b", 2);
+    v.insert("c", 3);
+
+    assert_eq!(v.get_index_of("b"), Some(1));
+    assert_eq!(v.get_full("c"), Some((2, &"c", &3)));
+    assert_eq!(v.get_index(0), Some((&"a", &1)));
+    assert_eq!(v.get_index(3), None);
+
+    v.move_index(0, 2);
+    assert!(v.keys().eq(["b", "c", "a"].iter()));
+    v.swap_indices(0, 1);
+    assert!(v.keys().eq(["c", "b", "a"].iter()));
+
+    assert_eq!(v.insert_at(0, "a", 4), Some(1));
+    assert_eq!(v.insert_at(1, "d", 5), None);
+    assert!(v.keys().eq(["a", "d", "c", "b"].iter()));
+
+    assert_eq!(v.shift_remove_index(1), Some(("d", 5)));
+    assert_eq!(v.swap_remove_index(0), Some(("a", 4)));
+    assert!(v.keys().eq(["b", "c"].iter()));
+
+    assert_eq!(v.insert_at(2, "b", 6), Some(2));
+    assert_eq!(v.insert_at(2, "e", 7), None);
+    assert!(v.iter().eq([(&"c", &3), (&"b", &6), (&"e", &7)]));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| v.insert_at(4, "c", 8)));
+    assert!(result.is_err());
+    assert_eq!(v["c"], 3);
+}
+
+#[test]
+fn filtering () {
+    let mut v = (0..10).map(|x| (x, x * 10)).collect::<VecMap<_, _>>();
+    v.retain(|k, _| k % 3 != 0);
+    assert!(v.keys().eq([1, 2, 4, 5, 7, 8].iter()));
+
+    let odd = v.extract_if(|k, _| k % 2 == 1).collect::<Vec<_>>();
+    assert_eq!(odd, [(1, 10), (5, 50), (7, 70)]);
+    assert!(v.keys().eq([2, 4, 8].iter()));
+
+    // Dropping the iterator early keeps the unvisited entries
+    assert_eq!(v.extract_if(|_, _| true).next(), Some((2, 20)));
+    assert!(v.keys().eq([4, 8].iter()));
+
+    let (small, big) = v.partition(|k, _| *k < 5);
+    assert!(small.keys().eq([4].iter()));
+    assert!(big.keys().eq([8].iter()));
+}
+
+#[test]
+fn sorting () {
+    let mut v = VecMap::new();
+    v.insert("c", 1);
+    v.insert("a", 3);
+    v.insert("d", 2);
+    v.insert("b", 4);
+
+    let top = v.top_k_by(2, |(_, x), (_, y)| y.cmp(x)).collect::<Vec<_>>();
+    assert_eq!(top, [(&"b", &4), (&"a", &3)]);
+
+    v.sort_by_values();
+    assert!(v.keys().eq(["c", "d", "a", "b"].iter()));
+
+    let v = match v.into_binary_map() {
+        Ok(_) => unreachable!(),
+        Err(mut v) => {
+            v.sort_keys();
+            v.into_binary_map().unwrap()
+        }
+    };
+    assert_eq!(v.get("d"), Some(&2));
+}
+
+#[test]
+fn primitive_keys () {
+    let v = (0..100u32).map(|x| (x, x as u64)).collect::<VecMap<_, _>>();
+    for i in 0..100u32 {
+        assert_eq!(v.get(&i), Some(&(i as u64)));
+    }
+    assert_eq!(v.get(&100), None);
+
+    let v = ('a'..='z').enumerate().map(|(i, c)| (c, i)).collect::<VecMap<_, _>>();
+    assert_eq!(v.get_index_of(&'q'), Some(16));
+    assert!(!v.contains_key(&'A'));
+}
+
+#[test]
+fn self_organizing () {
+    let mut v = (0..5).map(|x| (x, x * 10)).collect::<SelfOrganizingVecMap<_, _>>();
+    assert_eq!(v.get_promote(&3), Some(&30));
+    assert!(v.keys().eq([3, 0, 1, 2, 4].iter()));
+    assert_eq!(v.get(&4), Some(&40));
+    assert_eq!(v.get_index_of(&4), Some(4));
+
+    let prev = v.into_policy::<Ordered>();
+    let mut v = SelfOrganizingVecMap::with_policy(Transpose);
+    v.extend(prev);
+    *v.get_promote_mut(&4).unwrap() += 1;
+    assert!(v.iter().eq([(&3, &30), (&0, &0), (&1, &10), (&4, &41), (&2, &20)]));
+    assert_eq!(v.promote(&3), Some(0));
+    assert_eq!(v.promote(&5), None);
+
+    assert_eq!(v.remove(&0), Some(0));
+    assert!(v.keys().eq([3, 1, 4, 2].iter()));
+
+    *v.entry(2).or_insert(0) += 1;
+    v.entry(5).or_insert(50);
+    assert_eq!(v.get_key_value_promote(&2), Some((&2, &21)));
+    assert!(v.keys().eq([3, 1, 2, 4, 5].iter()));
+
+    let mut v = SelfOrganizingVecMap::<_, _, ArrayVec<_, 3>>::default();
+    assert_eq!(v.checked_extend([(1, 'a'), (2, 'b'), (3, 'c')]), Ok(()));
+    assert_eq!(v.get_promote(&3), Some(&'c'));
+    assert!(v.keys().eq([3, 1, 2].iter()));
+}
+
+#[test]
+fn entry_api () {
+    let mut v = VecMap::<String, i32>::new();
+    *v.entry("a".into()).or_insert(1) += 1;
+    *v.entry("a".into()).or_insert(10) += 1;
+    v.entry("b".into()).or_insert_with(|| 5);
+    v.entry("c".into()).or_insert_with_key(|k| k.len() as i32);
+    v.entry("d".into()).or_default();
+    v.entry("d".into()).and_modify(|x| *x += 7).or_default();
+    assert_eq!(v.entry("b".into()).key(), "b");
+    assert_eq!(v.get("a"), Some(&3));
+    assert_eq!(v.get("c"), Some(&1));
+    assert_eq!(v.get("d"), Some(&7));
+
+    let e = v.entry("e".into()).insert_entry(9);
+    assert_eq!((e.key().as_str(), *e.get()), ("e", 9));
+    assert_eq!(e.remove_entry(), ("e".into(), 9));
+
+    match v.entry("b".into()) {
+        Entry::Occupied(e) => assert_eq!(e.replace_entry(6), ("b".into(), 5)),
+        Entry::Vacant(_) => unreachable!()
+    }
+    match v.entry("b".into()) {
+        Entry::Occupied(e) => assert_eq!(e.replace_key(), "b"),
+        Entry::Vacant(_) => unreachable!()
+    }
+    assert_eq!(v.get("b"), Some(&6));
+}
+
+#[test]
+fn try_insert () {
+    let mut v = VecMap::new();
+    assert_eq!(v.try_insert("a", 1).ok().copied(), Some(1));
+
+    let err = v.try_insert("a", 2).unwrap_err();
+    assert_eq!((*err.entry.key(), *err.entry.get(), err.value), ("a", 1, 2));
+    assert_eq!(err.to_string(), r#"failed to insert 2, key "a" already exists with value 1"#);
+    assert_eq!(v.get("a"), Some(&1));
+}
+
+#[test]
+fn get_many () {
+    let mut v = VecMap::new();
+    v.insert("alice", 100);
+    v.insert("bob", 50);
+    v.insert("carol", 0);
+
+    let [from, to] = v.get_many_mut(["alice", "carol"]).unwrap();
+    *from -= 30;
+    *to += 30;
+    assert_eq!(v.get_many(["alice", "bob", "carol"]), Some([&70, &50, &30]));
+
+    assert!(v.get_many_mut(["alice", "alice"]).is_none());
+    assert!(v.get_many_mut(["alice", "dave"]).is_none());
+    assert!(v.get_many(["dave"]).is_none());
+
+    let [bob] = unsafe { v.get_many_unchecked_mut(["bob"]) }.unwrap();
+    *bob = 0;
+    assert_eq!(v.get("bob"), Some(&0));
+}
+
+#[test]
+fn find_by () {
+    let mut v = VecMap::new();
+    v.insert("Hello".to_string(), 1);
+    v.insert("World".to_string(), 2);
+
+    assert_eq!(v.position_by(|k| k.eq_ignore_ascii_case("world")), Some(1));
+    assert_eq!(v.find_by(|k| k.eq_ignore_ascii_case("HELLO")).map(|(i, k, v)| (i, k.as_str(), *v)), Some((0, "Hello", 1)));
+    *v.find_by_mut(|k| k.starts_with('W')).unwrap().2 += 10;
+    assert_eq!(v.get("World"), Some(&12));
+    assert!(v.find_by(|k| k.is_empty()).is_none());
+}
+
+#[test]
+fn checked_constructors () {
+    #[cfg(feature = "allocator-api2")]
+    use allocator_api2::vec::Vec;
+
+    let entries = Vec::from([(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e'), (1, 'f')]);
+
+    let first = VecMap::from_vec(entries.clone(), DuplicatePolicy::KeepFirst).unwrap();
+    assert!(first.iter().eq([(&1, &'a'), (&2, &'b'), (&3, &'d')]));
+
+    let last = VecMap::from_vec_ord(entries.clone(), DuplicatePolicy::KeepLast).unwrap();
+    assert!(last.iter().eq([(&1, &'f'), (&2, &'e'), (&3, &'d')]));
+    assert!(last.iter().eq(&VecMap::from_vec(entries.clone(), DuplicatePolicy::KeepLast).unwrap()));
+
+    let err = VecMap::<_, _>::try_from(entries.clone()).unwrap_err();
+    assert_eq!(err.indices(), &[2, 4, 5]);
+    assert_eq!(err.into_inner(), entries);
+    assert_eq!(VecMap::from_vec_ord(entries, DuplicatePolicy::Error).unwrap_err().indices(), &[2, 4, 5]);
+
+    assert!(VecMap::<_, _>::try_from(Vec::from([(1, 'a'), (2, 'b')])).is_ok());
+}
+
+#[test]
+fn capacity () {
+    let mut v = VecMap::<u64, u64>::new();
+    v.reserve(10);
+    assert!(v.capacity() >= 10);
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 0);
+
+    assert!(v.try_reserve(usize::MAX).is_err());
+    assert_eq!(v.try_insert_alloc(1, 1), Ok(None));
+    assert_eq!(v.try_insert_alloc(1, 2), Ok(Some(1)));
+    assert!(v.try_extend((2..5).map(|x| (x, x))).is_ok());
+    assert_eq!(v.len(), 4);
+
+    match v.entry(5) {
+        Entry::Vacant(entry) => assert_eq!(entry.try_insert(5).map(|x| *x), Ok(5)),
+        Entry::Occupied(_) => unreachable!()
+    }
+    assert_eq!(v.get(&5), Some(&5));
+}
+
+#[test]
+fn set_algebra () {
+    let a = (0..6).map(|x| (x, 'a')).collect::<VecMap<_, _>>();
+    let b = (3..9).rev().map(|x| (x, 'b')).collect::<VecMap<_, _>>();
+
+    assert!(a.union(&b).map(|(k, _)| *k).eq([0, 1, 2, 3, 4, 5, 8, 7, 6]));
+    assert!(a.intersection(&b).eq([(&3, &'a'), (&4, &'a'), (&5, &'a')]));
+    assert!(b.difference(&a).map(|(k, _)| *k).eq([8, 7, 6]));
+    assert!(a.symmetric_difference(&b).map(|(k, _)| *k).eq([0, 1, 2, 8, 7, 6]));
+
+    let inner = &(&a & &b) - &(2..4).map(|x| (x, 'c')).collect();
+    assert!(inner.keys().eq([4, 5].iter()));
+    assert_eq!((&a | &b).len(), 9);
+    assert_eq!((&a ^ &b).len(), 6);
+
+    assert!(inner.is_subset(&a) && b.is_superset(&inner));
+    assert!(!a.is_subset(&b));
+    assert!(!a.is_disjoint(&b));
+}
+
+#[test]
+fn literal () {
+    let v = vector_mapp::vecmap! { 3 => 'c', 1 => 'a', 2 => 'b', };
+    assert!(v.iter().eq([(&3, &'c'), (&1, &'a'), (&2, &'b')]));
+    assert_eq!(v.capacity(), 3);
+
+    let empty: VecMap<u8, ()> = vector_mapp::vecmap! {};
+    assert!(empty.is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "duplicate keys at indices [2]"]
+fn literal_duplicate () {
+    vector_mapp::vecmap! { 1 => 'a', 2 => 'b', 1 => 'c' };
+}
+
+#[cfg(feature = "allocator-api2")]
+#[test]
+fn allocator_api2 () {
+    use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+    use core::{cell::Cell, ptr::NonNull};
+    use vector_mapp::{binary::BinaryMap, r#box::BoxMap};
+
+    #[derive(Clone, Copy)]
+    struct Counting<'a>(&'a Cell<usize>);
+
+    unsafe impl Allocator for Counting<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    let count = Cell::new(0);
+    let mut v = VecMap::with_capacity_in(2, Counting(&count));
+    v.insert(2, 'b');
+    v.insert(1, 'a');
+    *v.entry(3).or_insert('x') = 'c';
+    assert_eq!(count.get(), 2);
+
+    v.sort_keys();
+    let b: BinaryMap<_, _, _> = v.into_binary_map().ok().unwrap();
+    assert!(b.keys().eq([1, 2, 3].iter()));
+    let bx = BoxMap::try_from(allocator_api2::vec::Vec::from(b.into_box())).ok().unwrap();
+    assert_eq!(bx.get(&3), Some(&'c'));
+    assert!(count.get() >= 2);
+
+    let count = Cell::new(0);
+    let v = vector_mapp::vecmap! { in Counting(&count); 1 => 'a', 2 => 'b' };
+    assert_eq!((v.len(), count.get()), (2, 1));
+    // The wrapper maps take the allocator through their storage
+    use vector_mapp::{set::VecSet, small::SmallVecMap, soa::SoaVecMap, vec::MoveToFront};
+
+    let count = Cell::new(0);
+    let mut set = VecSet::new_in(Counting(&count));
+    set.insert(1);
+    assert_eq!(count.get(), 1);
+
+    let mut small = SmallVecMap::<_, _, 1, _>::new_in(Counting(&count));
+    small.insert(1, 'a');
+    assert_eq!(count.get(), 1);
+    small.insert(2, 'b');
+    assert_eq!(count.get(), 2);
+
+    let mut soa = SoaVecMap::new_in(Counting(&count));
+    soa.insert(1, 'a');
+    assert_eq!(count.get(), 4);
+
+    let mut so = SelfOrganizingVecMap::with_policy_in(MoveToFront, Counting(&count));
+    so.insert(1, 'a');
+    so.insert(2, 'b');
+    assert_eq!(so.get_promote(&2), Some(&'b'));
+    assert!(so.keys().eq([2, 1].iter()));
+    assert_eq!(count.get(), 5);
+}